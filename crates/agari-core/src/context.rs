@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::parse::TileCounts;
 use crate::tile::{Honor, Tile};
+use crate::yaku::Yaku;
 
 /// How the hand was won
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,7 +16,7 @@ pub enum WinType {
 }
 
 /// Complete game context needed for scoring
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GameContext {
     // === Win condition ===
     pub win_type: WinType,
@@ -43,6 +44,9 @@ pub struct GameContext {
     pub is_rinshan: bool,
     /// Ron on another player's added kan tile (chankan)
     pub is_chankan: bool,
+    /// The tile robbed from the opponent's added kan (for chankan validation)
+    #[serde(default)]
+    pub chankan_tile: Option<Tile>,
     /// Last tile of the game (haitei for tsumo, houtei for ron)
     pub is_last_tile: bool,
     /// Dealer's first draw win (tenhou) - only valid for dealer + tsumo + first draw
@@ -55,10 +59,126 @@ pub struct GameContext {
     pub dora_indicators: Vec<Tile>,
     /// Ura dora indicators (revealed only on riichi win)
     pub ura_dora_indicators: Vec<Tile>,
+    /// Kan dora indicators (revealed by kan declarations), counted as regular dora
+    #[serde(default)]
+    pub kan_dora_indicators: Vec<Tile>,
+    /// Only count kan dora when the hand holds a kan and won on the rinshan draw
+    #[serde(default)]
+    pub rinshan_reveals_dora: bool,
 
     // === Akadora (red fives) ===
     /// Number of red fives in the winning hand
     pub aka_count: u8,
+    /// The winning tile itself is a red five
+    #[serde(default)]
+    pub winning_tile_is_aka: bool,
+
+    // === Table state ===
+    /// Repeat counters (honba) on the table, added to the winner's payment
+    #[serde(default)]
+    pub honba: u8,
+    /// Riichi deposits (kyotaku) on the table, 1000 each, collected by the winner
+    #[serde(default)]
+    pub riichi_sticks: u8,
+
+    // === Display options ===
+    /// List the dragon yakuhai as part of shousangen instead of individually
+    /// (see `yaku::displayed_yaku`; total han is unchanged)
+    #[serde(default)]
+    pub merge_shousangen_yakuhai: bool,
+
+    // === Rules ===
+    /// Rule variations applied during yaku detection and scoring
+    #[serde(default)]
+    pub rules: YakuRules,
+    /// Basic points for each named limit (mangan and above)
+    #[serde(default)]
+    pub limits: ScoreLimits,
+}
+
+/// Rule variations that change which yaku count and how han is scored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct YakuRules {
+    /// Open tanyao (kuitan) counts as a yaku
+    #[serde(alias = "allow_open_tanyao")]
+    pub kuitan_allowed: bool,
+    /// 13+ han without a yakuman yaku scores as yakuman (otherwise sanbaiman)
+    pub kazoe_yakuman: bool,
+    /// Round 4 han 30 fu and 3 han 60 fu up to mangan (kiriage mangan)
+    #[serde(alias = "kiriage_mangan")]
+    pub kiriage: bool,
+    /// Allow local (non-standard) yaku; none are detected yet
+    pub allow_local_yaku: bool,
+    /// Score a wait that reads as both pinfu's ryanmen and a fu-paying shape
+    /// both ways and keep the higher payment (see `scoring::Interpretation::new`);
+    /// when off, the highest-fu wait is always used and pinfu is not awarded
+    pub prefer_pinfu: bool,
+    /// Yaku that are never awarded under these rules (fu is unaffected, so
+    /// disabling menzen tsumo still keeps the +2 tsumo fu)
+    pub disabled_yaku: Vec<Yaku>,
+}
+
+impl Default for YakuRules {
+    fn default() -> Self {
+        YakuRules {
+            kuitan_allowed: true,
+            kazoe_yakuman: true,
+            kiriage: false,
+            allow_local_yaku: false,
+            prefer_pinfu: true,
+            disabled_yaku: Vec::new(),
+        }
+    }
+}
+
+/// Basic points awarded at each named limit, before dealer/tsumo multipliers
+///
+/// Double yakuman is always twice `yakuman`. Normal hands are capped at `mangan`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreLimits {
+    pub mangan: u32,
+    pub haneman: u32,
+    pub baiman: u32,
+    pub sanbaiman: u32,
+    pub yakuman: u32,
+}
+
+impl Default for ScoreLimits {
+    fn default() -> Self {
+        ScoreLimits {
+            mangan: 2000,
+            haneman: 3000,
+            baiman: 4000,
+            sanbaiman: 6000,
+            yakuman: 8000,
+        }
+    }
+}
+
+impl YakuRules {
+    /// Check if a yaku may be awarded under these rules
+    pub fn allows(&self, yaku: Yaku, is_open: bool) -> bool {
+        if is_open && yaku == Yaku::Tanyao && !self.kuitan_allowed {
+            return false;
+        }
+        !self.disabled_yaku.contains(&yaku)
+    }
+}
+
+/// All situational boolean flags, for setting them on a context at once
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SituationalFlags {
+    pub open: bool,
+    pub riichi: bool,
+    pub double_riichi: bool,
+    pub ippatsu: bool,
+    pub rinshan: bool,
+    pub chankan: bool,
+    pub last_tile: bool,
+    pub tenhou: bool,
+    pub chiihou: bool,
 }
 
 impl GameContext {
@@ -75,12 +195,21 @@ impl GameContext {
             is_ippatsu: false,
             is_rinshan: false,
             is_chankan: false,
+            chankan_tile: None,
             is_last_tile: false,
             is_tenhou: false,
             is_chiihou: false,
             dora_indicators: Vec::new(),
             ura_dora_indicators: Vec::new(),
+            kan_dora_indicators: Vec::new(),
+            rinshan_reveals_dora: false,
             aka_count: 0,
+            winning_tile_is_aka: false,
+            honba: 0,
+            riichi_sticks: 0,
+            merge_shousangen_yakuhai: false,
+            rules: YakuRules::default(),
+            limits: ScoreLimits::default(),
         }
     }
 
@@ -127,6 +256,13 @@ impl GameContext {
         self
     }
 
+    /// Builder-style: set chankan, robbing `tile` from an added kan
+    pub fn with_chankan_tile(mut self, tile: Tile) -> Self {
+        self.is_chankan = true;
+        self.chankan_tile = Some(tile);
+        self
+    }
+
     /// Builder-style: set last tile (haitei/houtei)
     pub fn last_tile(mut self) -> Self {
         self.is_last_tile = true;
@@ -145,29 +281,208 @@ impl GameContext {
         self
     }
 
+    /// Builder-style: set every situational flag that is true in `flags`
+    ///
+    /// Equivalent to calling the individual builders (e.g., `.riichi().ippatsu()`);
+    /// flags that are false leave the context unchanged.
+    pub fn with_flags(mut self, flags: SituationalFlags) -> Self {
+        if flags.open {
+            self = self.open();
+        }
+        if flags.riichi {
+            self = self.riichi();
+        }
+        if flags.double_riichi {
+            self = self.double_riichi();
+        }
+        if flags.ippatsu {
+            self = self.ippatsu();
+        }
+        if flags.rinshan {
+            self = self.rinshan();
+        }
+        if flags.chankan {
+            self = self.chankan();
+        }
+        if flags.last_tile {
+            self = self.last_tile();
+        }
+        if flags.tenhou {
+            self = self.tenhou();
+        }
+        if flags.chiihou {
+            self = self.chiihou();
+        }
+        self
+    }
+
     /// Builder-style: add dora indicator(s)
     pub fn with_dora(mut self, indicators: Vec<Tile>) -> Self {
         self.dora_indicators = indicators;
         self
     }
 
+    /// Builder-style: set dora from the dora tiles themselves, storing their indicators
+    pub fn with_dora_tiles(self, tiles: Vec<Tile>) -> Self {
+        self.with_dora(tiles.into_iter().map(dora_to_indicator).collect())
+    }
+
     /// Builder-style: add ura dora indicator(s)
     pub fn with_ura_dora(mut self, indicators: Vec<Tile>) -> Self {
         self.ura_dora_indicators = indicators;
         self
     }
 
+    /// Builder-style: add kan dora indicator(s)
+    pub fn with_kan_dora(mut self, indicators: Vec<Tile>) -> Self {
+        self.kan_dora_indicators = indicators;
+        self
+    }
+
+    /// Builder-style: only count kan dora on a rinshan win with a kan
+    pub fn rinshan_reveals_dora(mut self) -> Self {
+        self.rinshan_reveals_dora = true;
+        self
+    }
+
     /// Builder-style: set aka (red five) count
     pub fn with_aka(mut self, count: u8) -> Self {
         self.aka_count = count;
         self
     }
 
+    /// Builder-style: set the number of honba (repeat counters)
+    pub fn with_honba(mut self, honba: u8) -> Self {
+        self.honba = honba;
+        self
+    }
+
+    /// Builder-style: set the number of riichi sticks (kyotaku) on the table
+    pub fn with_riichi_sticks(mut self, riichi_sticks: u8) -> Self {
+        self.riichi_sticks = riichi_sticks;
+        self
+    }
+
+    /// Builder-style: mark the winning tile as a red five
+    pub fn with_aka_winning_tile(mut self) -> Self {
+        self.winning_tile_is_aka = true;
+        self
+    }
+
+    /// Builder-style: list dragon yakuhai merged into shousangen
+    pub fn merge_shousangen_yakuhai(mut self) -> Self {
+        self.merge_shousangen_yakuhai = true;
+        self
+    }
+
+    /// Builder-style: replace all rule variations at once
+    pub fn with_rules(mut self, rules: YakuRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Builder-style: use a custom score limit table
+    pub fn with_limits(mut self, limits: ScoreLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Builder-style: disallow open tanyao (no kuitan)
+    pub fn no_kuitan(mut self) -> Self {
+        self.rules.kuitan_allowed = false;
+        self
+    }
+
+    /// Builder-style: allow or disallow open tanyao (`rules.kuitan_allowed`)
+    pub fn with_allow_open_tanyao(mut self, allow_open_tanyao: bool) -> Self {
+        self.rules.kuitan_allowed = allow_open_tanyao;
+        self
+    }
+
+    /// Whether tanyao counts on an open hand (`rules.kuitan_allowed`)
+    pub fn allow_open_tanyao(&self) -> bool {
+        self.rules.kuitan_allowed
+    }
+
+    /// Builder-style: cap counted yakuman at sanbaiman
+    pub fn no_kazoe_yakuman(mut self) -> Self {
+        self.rules.kazoe_yakuman = false;
+        self
+    }
+
+    /// Builder-style: enable kiriage mangan
+    pub fn kiriage(mut self) -> Self {
+        self.rules.kiriage = true;
+        self
+    }
+
+    /// Builder-style: turn kiriage mangan on or off
+    ///
+    /// Sets `rules.kiriage`, which `calculate_score` hands to
+    /// `determine_score_level_with_rules`.
+    pub fn with_kiriage_mangan(mut self, kiriage_mangan: bool) -> Self {
+        self.rules.kiriage = kiriage_mangan;
+        self
+    }
+
+    /// Whether 4 han 30 fu and 3 han 60 fu round up to mangan (`rules.kiriage`)
+    pub fn kiriage_mangan(&self) -> bool {
+        self.rules.kiriage
+    }
+
+    /// Builder-style: always score the highest-fu wait instead of pinfu
+    pub fn no_prefer_pinfu(mut self) -> Self {
+        self.rules.prefer_pinfu = false;
+        self
+    }
+
+    /// Builder-style: allow local yaku
+    pub fn allow_local_yaku(mut self) -> Self {
+        self.rules.allow_local_yaku = true;
+        self
+    }
+
+    /// Builder-style: never award the given yaku
+    pub fn disable_yaku(mut self, yaku: Yaku) -> Self {
+        if !self.rules.disabled_yaku.contains(&yaku) {
+            self.rules.disabled_yaku.push(yaku);
+        }
+        self
+    }
+
     /// Check if this wind is a value wind (round or seat wind)
     pub fn is_value_wind(&self, wind: Honor) -> bool {
         wind == self.round_wind || wind == self.seat_wind
     }
 
+    /// Check if this honor is a value honor (yakuhai): any dragon, or the round/seat wind
+    pub fn is_value_honor(&self, honor: Honor) -> bool {
+        match honor {
+            Honor::White | Honor::Green | Honor::Red => true,
+            wind => self.is_value_wind(wind),
+        }
+    }
+
+    /// Check that a recorded chankan tile is consistent with the win
+    ///
+    /// Chankan robs the tile being added to a kan, so that tile must be the
+    /// winning tile. Contexts without a chankan tile always pass.
+    pub fn validate_chankan(&self) -> Result<(), String> {
+        let Some(robbed) = self.chankan_tile else {
+            return Ok(());
+        };
+        if !self.is_chankan {
+            return Err(format!("Chankan tile {} given without chankan", robbed));
+        }
+        match self.winning_tile {
+            Some(winning) if winning != robbed => Err(format!(
+                "Chankan tile {} does not match winning tile {}",
+                robbed, winning
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Check if hand is closed (menzen)
     pub fn is_closed(&self) -> bool {
         !self.is_open
@@ -208,6 +523,30 @@ pub fn indicator_to_dora(indicator: Tile) -> Tile {
     }
 }
 
+/// The indicator that makes `dora` the dora; the inverse of [`indicator_to_dora`]
+///
+/// Wraps the other way: 1 -> 9, East -> North, White -> Red.
+pub fn dora_to_indicator(dora: Tile) -> Tile {
+    match dora {
+        Tile::Suited { suit, value } => {
+            let prev_value = if value == 1 { 9 } else { value - 1 };
+            Tile::suited(suit, prev_value)
+        }
+        Tile::Honor(honor) => {
+            let prev_honor = match honor {
+                Honor::East => Honor::North,
+                Honor::South => Honor::East,
+                Honor::West => Honor::South,
+                Honor::North => Honor::West,
+                Honor::White => Honor::Red,
+                Honor::Green => Honor::White,
+                Honor::Red => Honor::Green,
+            };
+            Tile::honor(prev_honor)
+        }
+    }
+}
+
 /// Breakdown of dora counts by type
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct DoraCount {
@@ -251,12 +590,147 @@ pub fn count_dora_detailed(counts: &TileCounts, context: &GameContext) -> DoraCo
     result
 }
 
+/// Count the dora from kan dora indicators
+///
+/// With `rinshan_reveals_dora`, these only count when `has_kan` and the hand won on
+/// the rinshan draw; otherwise they always count.
+pub fn count_kan_dora(counts: &TileCounts, context: &GameContext, has_kan: bool) -> u8 {
+    if context.rinshan_reveals_dora && !(has_kan && context.is_rinshan) {
+        return 0;
+    }
+
+    context
+        .kan_dora_indicators
+        .iter()
+        .map(|indicator| {
+            counts
+                .get(&indicator_to_dora(*indicator))
+                .copied()
+                .unwrap_or(0)
+        })
+        .sum()
+}
+
+/// Dora (regular + ura + aka) the winning tile alone is worth
+///
+/// Ura dora only count with riichi, as in [`count_dora_detailed`]; the tile counts
+/// as aka only if the context marks it with `winning_tile_is_aka`.
+pub fn winning_tile_dora(winning_tile: Tile, context: &GameContext) -> u8 {
+    let matches = |indicators: &[Tile]| {
+        indicators
+            .iter()
+            .filter(|&&indicator| indicator_to_dora(indicator) == winning_tile)
+            .count() as u8
+    };
+
+    let regular = matches(&context.dora_indicators);
+    let ura = if context.is_riichi {
+        matches(&context.ura_dora_indicators)
+    } else {
+        0
+    };
+    let is_five = matches!(winning_tile, Tile::Suited { value: 5, .. });
+    let aka = u8::from(context.winning_tile_is_aka && is_five);
+
+    regular + ura + aka
+}
+
+/// Resolve each dora indicator to its dora tile and count the copies held
+///
+/// One entry per regular dora indicator, in indicator order; indicators that
+/// match nothing appear with a count of 0. Ura dora and akadora are not included.
+pub fn dora_contributions(counts: &TileCounts, context: &GameContext) -> Vec<(Tile, u8)> {
+    context
+        .dora_indicators
+        .iter()
+        .map(|indicator| {
+            let dora = indicator_to_dora(*indicator);
+            (dora, counts.get(&dora).copied().unwrap_or(0))
+        })
+        .collect()
+}
+
+/// Regular dora indicators whose dora tile isn't in the hand ("dead dora")
+///
+/// In indicator order, repeated if an indicator is repeated. Ura and kan dora
+/// indicators are not checked.
+pub fn dead_dora_indicators(counts: &TileCounts, context: &GameContext) -> Vec<Tile> {
+    context
+        .dora_indicators
+        .iter()
+        .copied()
+        .filter(|&indicator| {
+            counts
+                .get(&indicator_to_dora(indicator))
+                .copied()
+                .unwrap_or(0)
+                == 0
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::parse::{parse_hand, to_counts};
     use crate::tile::Suit;
 
+    #[test]
+    fn test_rule_builders_set_yaku_rules() {
+        let ctx = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .no_kuitan()
+            .no_kazoe_yakuman()
+            .kiriage()
+            .allow_local_yaku()
+            .no_prefer_pinfu()
+            .disable_yaku(Yaku::Ippatsu)
+            .disable_yaku(Yaku::Ippatsu);
+
+        let expected = YakuRules {
+            kuitan_allowed: false,
+            kazoe_yakuman: false,
+            kiriage: true,
+            allow_local_yaku: true,
+            prefer_pinfu: false,
+            disabled_yaku: vec![Yaku::Ippatsu],
+        };
+        assert_eq!(ctx.rules, expected);
+        assert_eq!(
+            GameContext::new(WinType::Ron, Honor::East, Honor::South).with_rules(expected.clone()),
+            ctx
+        );
+    }
+
+    #[test]
+    fn test_allow_open_tanyao_alias() {
+        let ctx = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        assert!(ctx.allow_open_tanyao());
+
+        let ctx = ctx.with_allow_open_tanyao(false);
+        assert!(!ctx.allow_open_tanyao());
+        assert_eq!(
+            ctx,
+            GameContext::new(WinType::Ron, Honor::East, Honor::South).no_kuitan()
+        );
+
+        let rules: YakuRules = serde_json::from_str(r#"{"allow_open_tanyao": false}"#).unwrap();
+        assert!(!rules.kuitan_allowed);
+    }
+
+    #[test]
+    fn test_kiriage_mangan_alias() {
+        let ctx = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        assert!(!ctx.kiriage_mangan());
+
+        let ctx = ctx.with_kiriage_mangan(true);
+        assert!(ctx.kiriage_mangan());
+        assert!(ctx.rules.kiriage);
+        assert!(!ctx.with_kiriage_mangan(false).rules.kiriage);
+
+        let rules: YakuRules = serde_json::from_str(r#"{"kiriage_mangan": true}"#).unwrap();
+        assert!(rules.kiriage);
+    }
+
     #[test]
     fn test_indicator_to_dora_suited() {
         // 1m indicator -> 2m dora
@@ -272,6 +746,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dora_to_indicator_inverts_indicator_to_dora() {
+        for tile in crate::tile::all_tiles() {
+            assert_eq!(dora_to_indicator(indicator_to_dora(tile)), tile);
+        }
+        assert_eq!(
+            dora_to_indicator(Tile::suited(Suit::Pin, 1)),
+            Tile::suited(Suit::Pin, 9)
+        );
+        assert_eq!(
+            dora_to_indicator(Tile::honor(Honor::East)),
+            Tile::honor(Honor::North)
+        );
+        assert_eq!(
+            dora_to_indicator(Tile::honor(Honor::White)),
+            Tile::honor(Honor::Red)
+        );
+    }
+
+    #[test]
+    fn test_with_dora_tiles() {
+        let tiles = parse_hand("666m456p789s11122z").unwrap();
+        let counts = to_counts(&tiles);
+
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::East)
+            .with_dora_tiles(vec![Tile::suited(Suit::Man, 6)]);
+
+        assert_eq!(context.dora_indicators, vec![Tile::suited(Suit::Man, 5)]);
+        assert_eq!(count_dora(&counts, &context), 3);
+    }
+
     #[test]
     fn test_indicator_to_dora_winds() {
         assert_eq!(
@@ -308,6 +813,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_flags_matches_chained_builders() {
+        let chained = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .double_riichi()
+            .ippatsu()
+            .rinshan()
+            .last_tile();
+
+        let flags = SituationalFlags {
+            double_riichi: true,
+            ippatsu: true,
+            rinshan: true,
+            last_tile: true,
+            ..Default::default()
+        };
+        let with_flags =
+            GameContext::new(WinType::Tsumo, Honor::East, Honor::South).with_flags(flags);
+
+        assert_eq!(with_flags, chained);
+        assert!(with_flags.is_riichi);
+    }
+
+    #[test]
+    fn test_with_flags_default_is_noop() {
+        let base = GameContext::new(WinType::Ron, Honor::South, Honor::West);
+        assert_eq!(base.clone().with_flags(SituationalFlags::default()), base);
+    }
+
     #[test]
     fn test_count_dora_simple() {
         // Hand with three 2m tiles, dora indicator is 1m (so 2m is dora)
@@ -320,6 +853,39 @@ mod tests {
         assert_eq!(count_dora(&counts, &context), 3);
     }
 
+    #[test]
+    fn test_dora_contributions() {
+        // 1m indicator -> 2m (two held), 8p indicator -> 9p (none held)
+        let tiles = parse_hand("22m345m456p789s111z").unwrap();
+        let counts = to_counts(&tiles);
+
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::East)
+            .with_dora(vec![Tile::suited(Suit::Man, 1), Tile::suited(Suit::Pin, 8)]);
+
+        assert_eq!(
+            dora_contributions(&counts, &context),
+            vec![
+                (Tile::suited(Suit::Man, 2), 2),
+                (Tile::suited(Suit::Pin, 9), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dead_dora_indicators() {
+        // 1m indicator -> 2m (held), 8p indicator -> 9p (dead)
+        let tiles = parse_hand("22m345m456p789s111z").unwrap();
+        let counts = to_counts(&tiles);
+
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::East)
+            .with_dora(vec![Tile::suited(Suit::Man, 1), Tile::suited(Suit::Pin, 8)]);
+
+        assert_eq!(
+            dead_dora_indicators(&counts, &context),
+            vec![Tile::suited(Suit::Pin, 8)]
+        );
+    }
+
     #[test]
     fn test_count_dora_with_ura() {
         // Hand with 2m and 5p
@@ -350,6 +916,30 @@ mod tests {
         assert_eq!(count_dora(&counts, &context), 3);
     }
 
+    #[test]
+    fn test_winning_tile_dora_red_five_dora() {
+        // 4p indicates 5p, and the winning 5p is red: worth 2
+        let five_pin = Tile::suited(Suit::Pin, 5);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(five_pin)
+            .with_dora(vec![Tile::suited(Suit::Pin, 4)])
+            .with_aka_winning_tile();
+        assert_eq!(winning_tile_dora(five_pin, &context), 2);
+
+        // Ura dora pointing at it only count with riichi
+        let context = context.with_ura_dora(vec![Tile::suited(Suit::Pin, 4)]);
+        assert_eq!(winning_tile_dora(five_pin, &context), 2);
+        assert_eq!(winning_tile_dora(five_pin, &context.riichi()), 3);
+    }
+
+    #[test]
+    fn test_winning_tile_dora_unrelated_tile() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_dora(vec![Tile::suited(Suit::Pin, 4)])
+            .with_aka_winning_tile();
+        assert_eq!(winning_tile_dora(Tile::suited(Suit::Man, 2), &context), 0);
+    }
+
     #[test]
     fn test_count_dora_with_aka() {
         let tiles = parse_hand("123m456p789s11122z").unwrap();
@@ -371,6 +961,54 @@ mod tests {
         assert!(!context.is_value_wind(Honor::North));
     }
 
+    #[test]
+    fn test_value_honor() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        // Dragons always
+        assert!(context.is_value_honor(Honor::White));
+        assert!(context.is_value_honor(Honor::Green));
+        assert!(context.is_value_honor(Honor::Red));
+
+        assert!(context.is_value_honor(Honor::East)); // Round wind
+        assert!(context.is_value_honor(Honor::South)); // Seat wind
+        assert!(!context.is_value_honor(Honor::West)); // Guest wind
+        assert!(!context.is_value_honor(Honor::North));
+    }
+
+    #[test]
+    fn test_chankan_tile_matching_winning_tile() {
+        let tile = Tile::suited(Suit::Pin, 3);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(tile)
+            .with_chankan_tile(tile);
+
+        assert!(context.is_chankan);
+        assert_eq!(context.validate_chankan(), Ok(()));
+    }
+
+    #[test]
+    fn test_chankan_tile_mismatch_rejected() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Pin, 3))
+            .with_chankan_tile(Tile::suited(Suit::Pin, 6));
+
+        assert!(context.validate_chankan().is_err());
+    }
+
+    #[test]
+    fn test_chankan_tile_without_chankan_rejected() {
+        let mut context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_chankan_tile(Tile::suited(Suit::Pin, 3));
+        context.is_chankan = false;
+
+        assert!(context.validate_chankan().is_err());
+        assert_eq!(
+            GameContext::new(WinType::Ron, Honor::East, Honor::South).validate_chankan(),
+            Ok(())
+        );
+    }
+
     #[test]
     fn test_builder_pattern() {
         let context = GameContext::new(WinType::Tsumo, Honor::South, Honor::West)
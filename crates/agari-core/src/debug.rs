@@ -0,0 +1,130 @@
+//! Developer dump for diagnosing unexpected scores
+//!
+//! Enabled with the `debug-tools` feature. [`debug_score`] writes out every step
+//! of scoring one hand so a surprising result can be traced to the decomposition,
+//! yaku or fu that caused it.
+
+use std::fmt::Write;
+
+use crate::context::{GameContext, WinType};
+use crate::display::{format_hand_normalized, format_structure_normalized};
+use crate::hand::{decompose_hand, decompose_hand_with_melds};
+use crate::parse::parse_hand_with_aka;
+use crate::scoring::{Interpretation, rank_interpretations};
+use crate::tile::{Honor, Tile};
+
+/// Dump the parsed tiles, every decomposition with its yaku, fu and score, and the
+/// interpretation that would be chosen
+///
+/// The hand is scored as an East-seat ron in the East round on `winning_tile`,
+/// open if it has open melds. Parse failures are reported in the returned text.
+pub fn debug_score(hand: &str, winning_tile: &str) -> String {
+    let mut out = String::new();
+
+    let parsed = match parse_hand_with_aka(hand) {
+        Ok(parsed) => parsed,
+        Err(e) => return format!("Parse error: {}\n", e),
+    };
+    let winning_tile: Tile = match winning_tile.parse() {
+        Ok(tile) => tile,
+        Err(e) => return format!("Parse error: {}\n", e),
+    };
+
+    let called_melds: Vec<_> = parsed
+        .called_melds
+        .iter()
+        .map(|cm| cm.meld.clone())
+        .collect();
+    let counts = parsed.concealed_counts();
+    let all_counts = parsed.all_counts();
+
+    let mut context = GameContext::new(WinType::Ron, Honor::East, Honor::East)
+        .with_winning_tile(winning_tile)
+        .with_aka(parsed.aka_count);
+    if called_melds.iter().any(|m| m.is_open()) {
+        context = context.open();
+    }
+
+    let structures = if called_melds.is_empty() {
+        decompose_hand(&counts)
+    } else {
+        decompose_hand_with_melds(&counts, &called_melds)
+    };
+
+    writeln!(out, "Tiles: {}", format_hand_normalized(&parsed)).unwrap();
+    writeln!(out, "Winning tile: {}", winning_tile).unwrap();
+    writeln!(out, "Structures: {}", structures.len()).unwrap();
+
+    for (i, structure) in structures.iter().enumerate() {
+        let interpretation = Interpretation::new(structure, &all_counts, &context);
+        let yaku_result = &interpretation.yaku;
+        let score = &interpretation.score;
+
+        writeln!(
+            out,
+            "\n#{} {}",
+            i + 1,
+            format_structure_normalized(structure, &parsed.aka_tiles)
+        )
+        .unwrap();
+        let yaku: Vec<String> = yaku_result
+            .yaku_list
+            .iter()
+            .map(|y| format!("{} ({})", y, y.effective_han(context.is_open).unwrap_or(0)))
+            .collect();
+        writeln!(out, "  yaku: {}", yaku.join(", ")).unwrap();
+        writeln!(out, "  dora: {}", yaku_result.dora_count).unwrap();
+        let b = &score.fu.breakdown;
+        writeln!(
+            out,
+            "  fu: base {} + menzen ron {} + tsumo {} + melds {} + pair {} + wait {} = {} -> {}",
+            b.base, b.menzen_ron, b.tsumo, b.melds, b.pair, b.wait, b.raw_total, score.fu.total
+        )
+        .unwrap();
+        writeln!(out, "  score: {}", score).unwrap();
+    }
+
+    // Chosen exactly as the scorer chooses
+    let ranked = rank_interpretations(&structures, &all_counts, &context);
+    match ranked.first() {
+        Some(best) if best.has_yaku() => {
+            let i = structures
+                .iter()
+                .position(|s| *s == best.structure)
+                .expect("ranked structure comes from the dumped list");
+            writeln!(out, "\nChosen: #{} {}", i + 1, best.score).unwrap();
+        }
+        Some(_) => writeln!(out, "\nChosen: none (no yaku)").unwrap(),
+        None => writeln!(out, "\nChosen: none (no valid structure)").unwrap(),
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_score_dump() {
+        // Pinfu + tanyao + iipeikou: a single decomposition, 3 han 30 fu on ron
+        let dump = debug_score("223344m345p678s55p", "2m");
+
+        assert!(dump.contains("Structures: 1"), "{dump}");
+        assert!(dump.contains("Chosen: #1 3han/30fu"), "{dump}");
+    }
+
+    #[test]
+    fn test_debug_score_chooses_like_the_scorer() {
+        // Ryanpeikou beats the chiitoitsu reading of the same tiles
+        let dump = debug_score("223344m223344p55s", "2m");
+        assert!(dump.contains("Structures: 2"), "{dump}");
+        assert!(dump.contains("Chosen: #2 5han/30fu"), "{dump}");
+    }
+
+    #[test]
+    fn test_debug_score_reports_parse_errors() {
+        assert!(debug_score("123x", "1m").starts_with("Parse error"));
+        assert!(debug_score("223344m345p678s55p", "8z").starts_with("Parse error"));
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! Supports both Unicode mahjong characters (🀇🀈🀉...) and ASCII fallback.
 
+use colored::Colorize;
+
 use crate::hand::{HandStructure, KanType, Meld};
-use crate::parse::ParsedHand;
+use crate::parse::{ParsedHand, TileCounts};
 use crate::tile::{Honor, KOKUSHI_TILES, Suit, Tile};
 
 /// Get the Unicode character for a tile with a trailing space for better rendering.
@@ -137,6 +139,19 @@ pub fn tiles_to_ascii(tiles: &[Tile]) -> String {
     result
 }
 
+/// Convert tile counts back to canonical notation (e.g., "123m456p789s11122z")
+///
+/// The inverse of [`crate::parse::to_counts`]: tiles are sorted and grouped by suit, honors use
+/// numeric `z` notation, and red fives are not distinguished.
+pub fn counts_to_string(counts: &TileCounts) -> String {
+    let mut tiles: Vec<Tile> = counts
+        .iter()
+        .flat_map(|(tile, &count)| std::iter::repeat_n(*tile, count as usize))
+        .collect();
+    tiles.sort();
+    tiles_to_ascii(&tiles)
+}
+
 /// Format a ParsedHand to normalized notation string (standard numeric notation)
 /// This produces machine-readable output suitable for JSON, using notation like "123m456p789s11144z"
 pub fn format_hand_normalized(parsed: &ParsedHand) -> String {
@@ -186,36 +201,93 @@ fn tile_to_notation(tile: &Tile) -> (String, char) {
     }
 }
 
-/// Format a meld using standard numeric notation (e.g., [123m], [111z])
-/// This is used for machine-readable JSON output
-fn format_meld_normalized(meld: &Meld) -> String {
-    match meld {
-        Meld::Shuntsu(start, _is_open) => {
-            if let Tile::Suited { suit, value } = start {
-                let suit_char = match suit {
-                    Suit::Man => 'm',
-                    Suit::Pin => 'p',
-                    Suit::Sou => 's',
-                };
-                format!("[{}{}{}{}]", value, value + 1, value + 2, suit_char)
-            } else {
-                "???".to_string()
-            }
+/// Tracks the red fives still to be marked while formatting a hand structure
+struct AkaMarker {
+    remaining: Vec<Tile>,
+}
+
+impl AkaMarker {
+    fn new(aka_tiles: &[Tile]) -> Self {
+        AkaMarker {
+            remaining: aka_tiles.to_vec(),
         }
-        Meld::Koutsu(tile, _is_open) => {
-            let (val, suit) = tile_to_notation(tile);
-            format!("[{}{}{}{}]", val, val, val, suit)
+    }
+
+    /// Returns true (and consumes one red five) if this tile should render as red
+    fn take(&mut self, tile: &Tile) -> bool {
+        match self.remaining.iter().position(|t| t == tile) {
+            Some(pos) => {
+                self.remaining.swap_remove(pos);
+                true
+            }
+            None => false,
         }
-        Meld::Kan(tile, _kan_type) => {
-            let (val, suit) = tile_to_notation(tile);
-            format!("[{}{}{}{}{}]", val, val, val, val, suit)
+    }
+}
+
+/// Tiles making up a meld, or None for a malformed (honor) sequence
+fn meld_tiles(meld: &Meld) -> Option<Vec<Tile>> {
+    match meld {
+        Meld::Shuntsu(start, _is_open) => match start {
+            Tile::Suited { suit, value } => Some(vec![
+                Tile::suited(*suit, *value),
+                Tile::suited(*suit, *value + 1),
+                Tile::suited(*suit, *value + 2),
+            ]),
+            Tile::Honor(_) => None,
+        },
+        Meld::Koutsu(tile, _is_open) => Some(vec![*tile; 3]),
+        Meld::Kan(tile, _kan_type) => Some(vec![*tile; 4]),
+    }
+}
+
+/// Format same-suit tiles in bracketed numeric notation (e.g., [406m]), marking red fives as 0
+fn format_group_normalized(tiles: &[Tile], aka: &mut AkaMarker) -> String {
+    let mut result = String::from("[");
+    let mut suit = 'z';
+    for tile in tiles {
+        let (val, s) = tile_to_notation(tile);
+        suit = s;
+        if aka.take(tile) {
+            result.push('0');
+        } else {
+            result.push_str(&val);
         }
     }
+    result.push(suit);
+    result.push(']');
+    result
+}
+
+/// Format a single tile for display, highlighting it if it is a red five
+fn format_tile_marked(tile: &Tile, use_unicode: bool, aka: &mut AkaMarker) -> String {
+    let is_red = aka.take(tile);
+    if use_unicode {
+        let uni = tile_to_unicode(tile);
+        if is_red { uni.red().to_string() } else { uni }
+    } else if is_red {
+        let (_, suit) = tile_to_notation(tile);
+        format!("0{}", suit)
+    } else {
+        tile_to_ascii(tile)
+    }
+}
+
+/// Format a meld using standard numeric notation (e.g., [123m], [111z])
+/// This is used for machine-readable JSON output
+fn format_meld_normalized(meld: &Meld, aka: &mut AkaMarker) -> String {
+    match meld_tiles(meld) {
+        Some(tiles) => format_group_normalized(&tiles, aka),
+        None => "???".to_string(),
+    }
 }
 
 /// Format a hand structure using standard numeric notation
 /// This produces machine-readable output suitable for JSON, e.g., "[123m] [456p] [789s] [111z] [44z]"
-pub fn format_structure_normalized(structure: &HandStructure) -> String {
+///
+/// Red fives listed in `aka_tiles` (see `ParsedHand::aka_tiles`) are written as 0 (e.g., "[406p]").
+pub fn format_structure_normalized(structure: &HandStructure, aka_tiles: &[Tile]) -> String {
+    let mut aka = AkaMarker::new(aka_tiles);
     match structure {
         HandStructure::Chiitoitsu { pairs } => {
             let mut sorted_pairs = pairs.clone();
@@ -223,10 +295,7 @@ pub fn format_structure_normalized(structure: &HandStructure) -> String {
 
             sorted_pairs
                 .iter()
-                .map(|t| {
-                    let (val, suit) = tile_to_notation(t);
-                    format!("[{}{}{}]", val, val, suit)
-                })
+                .map(|t| format_group_normalized(&[*t, *t], &mut aka))
                 .collect::<Vec<_>>()
                 .join(" ")
         }
@@ -248,10 +317,12 @@ pub fn format_structure_normalized(structure: &HandStructure) -> String {
             tile_strs.join(" ")
         }
         HandStructure::Standard { melds, pair } => {
-            let mut parts: Vec<String> = melds.iter().map(format_meld_normalized).collect();
+            let mut parts: Vec<String> = melds
+                .iter()
+                .map(|m| format_meld_normalized(m, &mut aka))
+                .collect();
 
-            let (val, suit) = tile_to_notation(pair);
-            parts.push(format!("[{}{}{}]", val, val, suit));
+            parts.push(format_group_normalized(&[*pair, *pair], &mut aka));
 
             parts.join(" ")
         }
@@ -260,80 +331,59 @@ pub fn format_structure_normalized(structure: &HandStructure) -> String {
 
 /// Format a meld for display
 pub fn format_meld(meld: &Meld, use_unicode: bool) -> String {
-    match meld {
-        Meld::Shuntsu(start, _is_open) => {
-            if let Tile::Suited { suit, value } = start {
-                let tiles = [
-                    Tile::suited(*suit, *value),
-                    Tile::suited(*suit, *value + 1),
-                    Tile::suited(*suit, *value + 2),
-                ];
-                if use_unicode {
-                    tiles_to_unicode(&tiles)
-                } else {
-                    format!(
-                        "[{}{}{}{}]",
-                        value,
-                        value + 1,
-                        value + 2,
-                        match suit {
-                            Suit::Man => 'm',
-                            Suit::Pin => 'p',
-                            Suit::Sou => 's',
-                        }
-                    )
-                }
-            } else {
-                "???".to_string()
-            }
-        }
-        Meld::Koutsu(tile, _is_open) => {
-            let tiles = [*tile, *tile, *tile];
-            if use_unicode {
-                tiles_to_unicode(&tiles)
-            } else {
-                let ascii = tile_to_ascii(tile);
-                format!("[{ascii}{ascii}{ascii}]")
-            }
-        }
-        Meld::Kan(tile, _kan_type) => {
-            let tiles = [*tile, *tile, *tile, *tile];
-            if use_unicode {
-                tiles_to_unicode(&tiles)
-            } else {
-                let ascii = tile_to_ascii(tile);
-                format!("[{ascii}{ascii}{ascii}{ascii}]")
-            }
-        }
+    format_meld_marked(meld, use_unicode, &mut AkaMarker::new(&[]))
+}
+
+fn format_meld_marked(meld: &Meld, use_unicode: bool, aka: &mut AkaMarker) -> String {
+    let Some(tiles) = meld_tiles(meld) else {
+        return "???".to_string();
+    };
+
+    if use_unicode {
+        tiles
+            .iter()
+            .map(|t| format_tile_marked(t, true, aka))
+            .collect()
+    } else if meld.is_sequence() {
+        // Sequences use compact notation: [123m]
+        format_group_normalized(&tiles, aka)
+    } else {
+        let ascii: String = tiles
+            .iter()
+            .map(|t| format_tile_marked(t, false, aka))
+            .collect();
+        format!("[{ascii}]")
     }
 }
 
 /// Format a hand structure for display
-pub fn format_structure(structure: &HandStructure, use_unicode: bool) -> String {
+///
+/// Red fives listed in `aka_tiles` (see `ParsedHand::aka_tiles`) are shown as 0
+/// in ASCII mode and highlighted in red in Unicode mode.
+pub fn format_structure(
+    structure: &HandStructure,
+    use_unicode: bool,
+    aka_tiles: &[Tile],
+) -> String {
+    let mut aka = AkaMarker::new(aka_tiles);
     match structure {
         HandStructure::Chiitoitsu { pairs } => {
             let mut sorted_pairs = pairs.clone();
             sorted_pairs.sort();
 
-            if use_unicode {
-                sorted_pairs
-                    .iter()
-                    .map(|t| {
-                        let uni = tile_to_unicode(t);
-                        format!("{uni}{uni}")
-                    })
-                    .collect::<Vec<_>>()
-                    .join(" ")
-            } else {
-                sorted_pairs
-                    .iter()
-                    .map(|t| {
-                        let ascii = tile_to_ascii(t);
-                        format!("[{ascii}{ascii}]")
-                    })
-                    .collect::<Vec<_>>()
-                    .join(" ")
-            }
+            sorted_pairs
+                .iter()
+                .map(|t| {
+                    let first = format_tile_marked(t, use_unicode, &mut aka);
+                    let second = format_tile_marked(t, use_unicode, &mut aka);
+                    if use_unicode {
+                        format!("{first}{second}")
+                    } else {
+                        format!("[{first}{second}]")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
         }
         HandStructure::Kokushi { pair } => {
             // Display all 13 kokushi tiles, with the pair tile shown twice
@@ -369,15 +419,17 @@ pub fn format_structure(structure: &HandStructure, use_unicode: bool) -> String
             }
         }
         HandStructure::Standard { melds, pair } => {
-            let mut parts: Vec<String> =
-                melds.iter().map(|m| format_meld(m, use_unicode)).collect();
+            let mut parts: Vec<String> = melds
+                .iter()
+                .map(|m| format_meld_marked(m, use_unicode, &mut aka))
+                .collect();
 
+            let first = format_tile_marked(pair, use_unicode, &mut aka);
+            let second = format_tile_marked(pair, use_unicode, &mut aka);
             if use_unicode {
-                let uni = tile_to_unicode(pair);
-                parts.push(format!("{uni}{uni}"));
+                parts.push(format!("{first}{second}"));
             } else {
-                let ascii = tile_to_ascii(pair);
-                parts.push(format!("[{ascii}{ascii}]"));
+                parts.push(format!("[{first}{second}]"));
             }
 
             parts.join(" ")
@@ -410,6 +462,7 @@ pub fn suit_name(suit: &Suit) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::{parse_hand, to_counts};
 
     #[test]
     fn test_tile_to_unicode() {
@@ -428,4 +481,69 @@ mod tests {
         ];
         assert_eq!(tiles_to_unicode(&tiles), "🀇 🀈 🀉 ");
     }
+
+    #[test]
+    fn test_counts_to_string_canonical() {
+        let tiles = parse_hand("e1m9p1m2z").unwrap();
+        let counts = to_counts(&tiles);
+        assert_eq!(counts_to_string(&counts), "11m9p12z");
+    }
+
+    #[test]
+    fn test_counts_to_string_round_trip() {
+        for hand in [
+            "123m456p789s11122z",
+            "1122m3344p5566s77z",
+            "19m19p19s1234567z1m",
+        ] {
+            let counts = to_counts(&parse_hand(hand).unwrap());
+            let notation = counts_to_string(&counts);
+            assert_eq!(to_counts(&parse_hand(&notation).unwrap()), counts);
+        }
+    }
+
+    #[test]
+    fn test_counts_to_string_empty() {
+        assert_eq!(counts_to_string(&TileCounts::new()), "");
+    }
+
+    #[test]
+    fn test_format_structure_marks_red_five_in_sequence() {
+        let parsed = crate::parse::parse_hand_with_aka("123m406p789s11122z").unwrap();
+        let counts = crate::parse::to_counts(&parsed.tiles);
+        let structures = crate::hand::decompose_hand(&counts);
+
+        let normalized = format_structure_normalized(&structures[0], &parsed.aka_tiles);
+        assert!(normalized.contains("[406p]"), "{}", normalized);
+
+        let ascii = format_structure(&structures[0], false, &parsed.aka_tiles);
+        assert!(ascii.contains("[406p]"), "{}", ascii);
+    }
+
+    #[test]
+    fn test_format_structure_marks_only_red_copies() {
+        // One red five among a 555p triplet: only one copy is shown as red
+        let parsed = crate::parse::parse_hand_with_aka("123m055p789s11122z").unwrap();
+        let counts = crate::parse::to_counts(&parsed.tiles);
+        let structures = crate::hand::decompose_hand(&counts);
+
+        let normalized = format_structure_normalized(&structures[0], &parsed.aka_tiles);
+        assert!(normalized.contains("[055p]"), "{}", normalized);
+        assert_eq!(normalized.matches('0').count(), 1);
+
+        let ascii = format_structure(&structures[0], false, &parsed.aka_tiles);
+        assert!(ascii.contains("[0p5p5p]"), "{}", ascii);
+    }
+
+    #[test]
+    fn test_format_structure_without_aka_unchanged() {
+        let parsed = crate::parse::parse_hand_with_aka("123m456p789s11122z").unwrap();
+        let counts = crate::parse::to_counts(&parsed.tiles);
+        let structures = crate::hand::decompose_hand(&counts);
+
+        assert_eq!(
+            format_structure_normalized(&structures[0], &[]),
+            "[123m] [456p] [789s] [111z] [22z]"
+        );
+    }
 }
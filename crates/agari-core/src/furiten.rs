@@ -0,0 +1,81 @@
+//! Furiten detection for Riichi Mahjong
+//!
+//! A tenpai player whose wait includes a tile from their own discards is furiten:
+//! they may still win by tsumo, but not by ron.
+
+use crate::parse::TileCounts;
+use crate::tile::Tile;
+use crate::wait::calculate_waits;
+
+/// Check if a tenpai hand is furiten given the player's own discards
+///
+/// `counts` holds the concealed tiles only (13, minus 3 per called meld); called
+/// melds are inferred from the tile count. A hand that isn't tenpai is never furiten.
+pub fn is_furiten(counts: &TileCounts, discards: &[Tile]) -> bool {
+    let waits = calculate_waits(counts);
+    discards.iter().any(|tile| waits.contains(tile))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{parse_hand, to_counts};
+    use crate::tile::{Honor, Suit};
+
+    fn counts(hand: &str) -> TileCounts {
+        to_counts(&parse_hand(hand).unwrap())
+    }
+
+    #[test]
+    fn test_furiten_on_discarded_wait() {
+        // Ryanmen 23m waits on 1m/4m; the 4m was discarded earlier
+        let hand = counts("23m456p789s11122z");
+        assert!(is_furiten(&hand, &[Tile::suited(Suit::Man, 4)]));
+        assert!(is_furiten(&hand, &[Tile::suited(Suit::Man, 1)]));
+    }
+
+    #[test]
+    fn test_not_furiten_on_unrelated_discards() {
+        let hand = counts("23m456p789s11122z");
+        assert!(!is_furiten(&hand, &[]));
+        assert!(!is_furiten(
+            &hand,
+            &[Tile::suited(Suit::Man, 5), Tile::suited(Suit::Pin, 1)]
+        ));
+    }
+
+    #[test]
+    fn test_furiten_with_called_meld() {
+        // (456p) called: 10 concealed tiles, still waiting on 1m/4m
+        let hand = counts("23m789s11122z");
+        assert!(is_furiten(&hand, &[Tile::suited(Suit::Man, 1)]));
+    }
+
+    #[test]
+    fn test_furiten_on_any_side_of_multi_sided_wait() {
+        // 23456m waits on 1m/4m/7m; discarding any one of them is furiten
+        let hand = counts("23456m456p789s11z");
+        assert_eq!(
+            calculate_waits(&hand),
+            vec![
+                Tile::suited(Suit::Man, 1),
+                Tile::suited(Suit::Man, 4),
+                Tile::suited(Suit::Man, 7)
+            ]
+        );
+        assert!(is_furiten(&hand, &[Tile::suited(Suit::Man, 7)]));
+    }
+
+    #[test]
+    fn test_furiten_on_tanki_wait() {
+        let hand = counts("123m456p789s1112z");
+        assert!(is_furiten(&hand, &[Tile::honor(Honor::South)]));
+        assert!(!is_furiten(&hand, &[Tile::honor(Honor::West)]));
+    }
+
+    #[test]
+    fn test_not_tenpai_is_not_furiten() {
+        let hand = counts("159m456p789s1122z");
+        assert!(!is_furiten(&hand, &[Tile::suited(Suit::Man, 1)]));
+    }
+}
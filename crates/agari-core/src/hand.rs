@@ -212,6 +212,59 @@ pub fn decompose_hand_with_melds(
     results
 }
 
+/// The tiles a decomposition was built from, in no particular order
+///
+/// Every structure returned by [`decompose_hand`] reconstructs to the multiset of
+/// tiles it was decomposed from (14 tiles, plus one per kan).
+pub fn reconstruct_tiles(structure: &HandStructure) -> Vec<Tile> {
+    match structure {
+        HandStructure::Standard { melds, pair } => collect_all_tiles(melds, *pair),
+        HandStructure::Chiitoitsu { pairs } => pairs.iter().flat_map(|&t| [t, t]).collect(),
+        HandStructure::Kokushi { pair } => {
+            let mut tiles = KOKUSHI_TILES.to_vec();
+            tiles.push(*pair);
+            tiles
+        }
+    }
+}
+
+/// Collect all tiles from melds and pair
+pub(crate) fn collect_all_tiles(melds: &[Meld], pair: Tile) -> Vec<Tile> {
+    let mut tiles = vec![pair, pair];
+    tiles.extend(collect_meld_tiles(melds));
+    tiles
+}
+
+/// Collect all tiles from melds
+pub(crate) fn collect_meld_tiles(melds: &[Meld]) -> Vec<Tile> {
+    let mut tiles = Vec::new();
+
+    for meld in melds {
+        match meld {
+            Meld::Koutsu(t, _) => {
+                tiles.push(*t);
+                tiles.push(*t);
+                tiles.push(*t);
+            }
+            Meld::Shuntsu(t, _) => {
+                tiles.push(*t);
+                if let Tile::Suited { suit, value } = t {
+                    tiles.push(Tile::suited(*suit, value + 1));
+                    tiles.push(Tile::suited(*suit, value + 2));
+                }
+            }
+            Meld::Kan(t, _) => {
+                tiles.push(*t);
+                tiles.push(*t);
+                tiles.push(*t);
+                tiles.push(*t);
+            }
+        }
+    }
+
+    tiles
+}
+
 /// Find all ways to form exactly `needed` melds from the given tiles
 fn find_all_meld_combinations(mut counts: TileCounts, needed: u32) -> Vec<Vec<Meld>> {
     // Remove zero-count entries
@@ -333,6 +386,21 @@ pub fn is_kokushi_13_wait(counts: &TileCounts) -> bool {
     counts.len() == 13
 }
 
+/// Check for kyuushu kyuuhai: a 13 or 14 tile starting hand holding at least nine
+/// distinct terminal/honor types, which may declare an abortive draw
+pub fn is_kyuushu_kyuuhai(counts: &TileCounts) -> bool {
+    let total: u8 = counts.values().sum();
+    if total != 13 && total != 14 {
+        return false;
+    }
+
+    let distinct = KOKUSHI_TILES
+        .iter()
+        .filter(|tile| counts.get(tile).copied().unwrap_or(0) > 0)
+        .count();
+    distinct >= 9
+}
+
 pub fn is_standard_hand(counts: &TileCounts) -> bool {
     for (&tile, &count) in counts {
         if count >= 2 {
@@ -450,6 +518,22 @@ mod tests {
         assert!(is_standard_hand(&counts));
     }
 
+    #[test]
+    fn test_kyuushu_kyuuhai_nine_types() {
+        // 1m 9m 1p 9p 1s 9s East South West: nine distinct types
+        let tiles = parse_hand("1199m19p19s123z456m").unwrap();
+        let counts = to_counts(&tiles);
+        assert!(is_kyuushu_kyuuhai(&counts));
+    }
+
+    #[test]
+    fn test_kyuushu_kyuuhai_eight_types() {
+        // Same hand with West swapped for a simple: only eight types
+        let tiles = parse_hand("1199m19p19s12z4567m").unwrap();
+        let counts = to_counts(&tiles);
+        assert!(!is_kyuushu_kyuuhai(&counts));
+    }
+
     // ===== Decomposition Tests =====
 
     #[test]
@@ -555,6 +639,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decompositions_reconstruct_input() {
+        let hands = [
+            "123m456p789s11122z",
+            "111222333m44455p",
+            "112233m445566p77s",
+            "1122m3344p5566s77z",
+            "11112222333344m",
+            "11123455678999m",
+            "19m19p19s12345677z",
+            "234567m234p345s88s",
+        ];
+
+        for hand in hands {
+            let counts = to_counts(&parse_hand(hand).unwrap());
+            let structures = decompose_hand(&counts);
+            assert!(!structures.is_empty(), "{hand} should decompose");
+            for structure in &structures {
+                let rebuilt = to_counts(&reconstruct_tiles(structure));
+                assert_eq!(rebuilt, counts, "{hand}: {structure:?}");
+            }
+        }
+    }
+
     // ===== Kan and Meld State Tests =====
 
     #[test]
@@ -1,10 +1,15 @@
 // src/lib.rs
 pub mod context;
+#[cfg(feature = "debug-tools")]
+pub mod debug;
 pub mod display;
+pub mod furiten;
 pub mod hand;
 pub mod parse;
+pub mod request;
 pub mod scoring;
 pub mod shanten;
+pub mod stats;
 pub mod tile;
 pub mod wait;
 pub mod yaku;
@@ -2,11 +2,12 @@
 //!
 //! A command-line tool for calculating the score of a Riichi Mahjong hand.
 
-use std::collections::HashSet;
-use std::process;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::{fs, process};
 
-use clap::Parser;
 use clap::builder::styling::{AnsiColor, Effects, Styles};
+use clap::{Parser, Subcommand};
 use colored::Colorize;
 use serde::Serialize;
 
@@ -17,14 +18,23 @@ use agari::{
         tile_to_ascii, tile_to_unicode,
     },
     hand::{HandStructure, decompose_hand, decompose_hand_with_melds},
-    parse::{TileCounts, parse_hand_with_aka, to_counts, validate_hand, validate_hand_with_melds},
-    scoring::{ScoreLevel, ScoringResult, calculate_score},
+    parse::{
+        ParseError, TileCounts, parse_hand_with_win, to_counts, validate_hand,
+        validate_hand_with_melds,
+    },
+    request::{ScoreRequest, build_context},
+    scoring::{
+        Interpretation, ScoreLevel, ScoreSummary, ScoringResult, calculate_score,
+        compare_interpretations, rank_interpretations,
+    },
     shanten::{
-        ShantenType, calculate_shanten_with_melds, calculate_ukeire_with_melds,
+        ShantenType, UkeireResult, calculate_shanten_with_melds, calculate_ukeire_with_melds,
         calculate_ukeire_with_melds_and_visible,
     },
-    tile::{Honor, Suit, Tile},
-    yaku::{Yaku, YakuResult, detect_yaku_with_context},
+    stats::YakuStats,
+    tile::{Honor, Tile, all_tiles},
+    wait::wait_type,
+    yaku::{YakuResult, detect_yaku_with_context, displayed_yaku},
 };
 
 const AFTER_HELP: &str = r#"HAND FORMAT:
@@ -55,7 +65,9 @@ EXAMPLES:
     agari "[1111m]222333m555p11z" -t      Hand with closed kan (15 tiles)
     agari "[1111m](2222p)345678s11z" -t   Hand with closed + open kan (16 tiles)
     agari "123m456p789s(rrr)whwh" -w wh   Open pon of Red dragon, White pair
-    agari 123m456p789s1112z --ukeire --visible 2z,2z  Practical ukeire with visible tiles"#;
+    agari 123m456p789s1112z --ukeire --visible 2z,2z  Practical ukeire with visible tiles
+    agari 123456m456p11z45s --ukeire --grid  Ukeire as a 34-tile grid
+    agari stats hands.txt                 Yaku statistics over a file of hands"#;
 
 fn styles() -> Styles {
     Styles::styled()
@@ -71,11 +83,16 @@ fn styles() -> Styles {
 #[command(styles = styles())]
 #[command(about = "Riichi Mahjong Hand Scoring Calculator")]
 #[command(after_help = AFTER_HELP)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
-    /// Hand notation (e.g., 123m456p789s11122z)
-    hand: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Hand notation (e.g., 123m456p789s11122z; mark a ron winning tile with + or a tsumo with =, e.g., 5+5s)
+    #[arg(required = true)]
+    hand: Option<String>,
 
-    /// Winning tile (e.g., 2m, 5z)
+    /// Winning tile (e.g., 2m, 5z); overrides a + marker in the hand
     #[arg(short = 'w', long = "win")]
     winning_tile: Option<String>,
 
@@ -148,23 +165,52 @@ struct Args {
     #[arg(long)]
     visible: Option<String>,
 
+    /// Show ukeire as a 34-tile grid (man, pin, sou, honors rows)
+    #[arg(long, requires = "ukeire")]
+    grid: bool,
+
     /// Use ASCII output instead of Unicode
     #[arg(long)]
     ascii: bool,
 
+    /// Don't infer a winning tile when none is given (no pinfu or wait fu)
+    #[arg(long)]
+    no_infer: bool,
+
     /// Show all possible interpretations
     #[arg(long)]
     all: bool,
 
+    /// With --all, show one table row per interpretation
+    #[arg(long, requires = "all")]
+    table: bool,
+
     /// Output results as JSON
     #[arg(long)]
     json: bool,
 
-    /// Disable colored output
+    /// Output results as CSV lines: hand,han,fu,score_level,total_points,yaku
+    #[arg(long, conflicts_with = "json")]
+    csv: bool,
+
+    /// Print the resolved game context as JSON and exit (for bug reports)
     #[arg(long)]
+    dump_context: bool,
+
+    /// Disable colored output
+    #[arg(long, global = true)]
     no_color: bool,
 }
 
+#[derive(Subcommand)]
+enum Command {
+    /// Score every hand in a file (one per line) and print yaku statistics
+    Stats {
+        /// File with one hand per line
+        file: PathBuf,
+    },
+}
+
 // JSON output structures
 #[derive(Serialize)]
 struct JsonOutput {
@@ -213,6 +259,11 @@ struct JsonInterpretation {
     dora: JsonDora,
     han: u8,
     fu: u8,
+    /// Wait the fu was scored from (absent without a winning tile)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_fu: Option<u8>,
     score_level: String,
     payment: JsonPayment,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -268,6 +319,8 @@ struct JsonFuBreakdown {
     wait: u8,
     raw: u8,
     rounded: u8,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    floored_to_open_minimum: bool,
 }
 
 #[derive(Serialize)]
@@ -276,6 +329,8 @@ struct JsonShantenOutput {
     description: String,
     best_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    bottleneck: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     ukeire: Option<JsonUkeire>,
 }
 
@@ -361,13 +416,20 @@ fn main() {
         colored::control::set_override(false);
     }
 
+    if let Some(Command::Stats { file }) = &args.command {
+        run_stats(file);
+        return;
+    }
+    let hand = args.hand.as_deref().unwrap_or_default();
+
     // Extract arguments
     let shanten_mode = args.shanten || args.ukeire;
     let ukeire_mode = args.ukeire;
     let riichi = args.riichi || args.double_riichi;
 
-    // Parse the hand
-    let parsed = match parse_hand_with_aka(&args.hand) {
+    // Parse the hand (a `+` or `=` may mark a ron or tsumo winning tile inline;
+    // the marker is applied when the context is built)
+    let (parsed, _) = match parse_hand_with_win(hand) {
         Ok(p) => p,
         Err(e) => {
             eprintln!("{} {}", "❌ Error parsing hand:".red().bold(), e);
@@ -407,23 +469,6 @@ fn main() {
     // If hand has open melds, mark hand as open
     let has_open_melds = parsed.called_melds.iter().any(|m| m.meld.is_open());
 
-    // Parse winds
-    let round_wind = match parse_wind(&args.round) {
-        Ok(w) => w,
-        Err(e) => {
-            eprintln!("{} {}", "❌".red().bold(), e);
-            process::exit(1);
-        }
-    };
-
-    let seat_wind = match parse_wind(&args.seat) {
-        Ok(w) => w,
-        Err(e) => {
-            eprintln!("{} {}", "❌".red().bold(), e);
-            process::exit(1);
-        }
-    };
-
     // Parse dora indicators
     let dora_indicators = match args.dora.as_ref().map(|s| parse_tile_list(s)).transpose() {
         Ok(d) => d.unwrap_or_default(),
@@ -452,7 +497,7 @@ fn main() {
         eprintln!("{} {}", "⚠️  Warning:".yellow().bold(), warning);
     }
 
-    // Parse winning tile
+    // Parse winning tile (core parsing is the same; this adds a hint for multi-tile input)
     let winning_tile = match args
         .winning_tile
         .as_ref()
@@ -466,68 +511,52 @@ fn main() {
         }
     };
 
-    // Build game context
-    let win_type = if args.tsumo {
-        WinType::Tsumo
-    } else {
-        WinType::Ron
+    // Build game context (an inline winning tile marker is applied here too)
+    let to_strings = |tiles: &[Tile]| tiles.iter().map(|t| t.to_string()).collect();
+    let request = ScoreRequest {
+        hand: hand.to_string(),
+        winning_tile: winning_tile.map(|t| t.to_string()),
+        is_tsumo: args.tsumo,
+        is_riichi: riichi,
+        is_double_riichi: args.double_riichi,
+        is_ippatsu: args.ippatsu,
+        round_wind: args.round.clone(),
+        seat_wind: args.seat.clone(),
+        dora_indicators: to_strings(&dora_indicators),
+        ura_dora_indicators: to_strings(&ura_indicators),
+        is_last_tile: args.last_tile,
+        is_rinshan: args.rinshan,
+        is_chankan: args.chankan,
+        chankan_tile: None,
+        is_tenhou: args.tenhou,
+        is_chiihou: args.chiihou,
+        is_open: args.open,
+        infer_winning_tile: !args.no_infer,
+        discards: vec![],
+        honba: 0,
+        riichi_sticks: 0,
+    };
+    let context = match build_context(&request) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{} {}", "❌".red().bold(), e);
+            process::exit(1);
+        }
     };
-    let mut context = GameContext::new(win_type, round_wind, seat_wind)
-        .with_dora(dora_indicators)
-        .with_ura_dora(ura_indicators)
-        .with_aka(parsed.aka_count);
-
-    // If winning tile is specified, use it; otherwise we'll infer it later
-    let explicit_winning_tile = winning_tile;
-    if let Some(wt) = explicit_winning_tile {
-        context = context.with_winning_tile(wt);
-    }
-
-    if args.open || has_open_melds {
-        context = context.open();
-    }
-
-    if args.double_riichi {
-        context = context.double_riichi();
-    } else if riichi {
-        context = context.riichi();
-    }
-
-    if args.ippatsu {
-        context = context.ippatsu();
-    }
-
-    if args.last_tile {
-        context = context.last_tile();
-    }
-
-    if args.rinshan {
-        context = context.rinshan();
-    }
-
-    if args.chankan {
-        context = context.chankan();
-    }
 
-    if args.tenhou {
-        context = context.tenhou();
-    }
+    // If no winning tile was given or marked, we'll infer it later
+    let explicit_winning_tile = context.winning_tile;
 
-    if args.chiihou {
-        context = context.chiihou();
+    if args.dump_context {
+        println!("{}", context_json(&context));
+        return;
     }
 
     // Convert to tile counts (for hand decomposition)
-    let counts = to_counts(&parsed.tiles);
+    let counts = parsed.concealed_counts();
 
     // For dora counting, we need ALL tiles including those in called melds
-    let all_tiles_counts = {
-        let mut all_tiles = parsed.tiles.clone();
-        for called_meld in &parsed.called_melds {
-            all_tiles.extend(&called_meld.tiles);
-        }
-        to_counts(&all_tiles)
-    };
+    let all_tiles_counts = parsed.all_counts();
 
     let use_unicode = !args.ascii;
 
@@ -561,6 +590,7 @@ fn main() {
                 &counts,
                 called_melds_count,
                 ukeire_mode,
+                args.grid,
                 use_unicode,
                 visible_counts.as_ref(),
             );
@@ -596,7 +626,8 @@ fn main() {
     //
     // If no winning tile was specified, we need to infer the best one.
     // Try each unique tile in the hand and pick the one that maximizes score.
-    let (mut results, context) = if explicit_winning_tile.is_none() {
+    // With --no-infer, the hand is scored without any winning tile instead.
+    let (mut results, context) = if explicit_winning_tile.is_none() && !args.no_infer {
         infer_best_winning_tile(&structures, &all_tiles_counts, context, &parsed.tiles)
     } else {
         let results: Vec<_> = structures
@@ -638,62 +669,7 @@ fn main() {
         let interpretations: Vec<JsonInterpretation> = results_to_show
             .iter()
             .map(|&(structure, yaku_result, score)| {
-                let yaku_list: Vec<JsonYaku> = yaku_result
-                    .yaku_list
-                    .iter()
-                    .map(|y| JsonYaku {
-                        name: yaku_name(y).to_string(),
-                        han: if context.is_open {
-                            y.han_open().unwrap_or(0)
-                        } else {
-                            y.han()
-                        },
-                        yakuman: y.is_yakuman(),
-                    })
-                    .collect();
-
-                let fu_breakdown = if score.fu.total != 25
-                    && score.fu.total != 20
-                    && score.fu.breakdown.raw_total > 20
-                {
-                    Some(JsonFuBreakdown {
-                        base: 20,
-                        menzen_ron: score.fu.breakdown.menzen_ron,
-                        tsumo: score.fu.breakdown.tsumo,
-                        melds: score.fu.breakdown.melds,
-                        pair: score.fu.breakdown.pair,
-                        wait: score.fu.breakdown.wait,
-                        raw: score.fu.breakdown.raw_total,
-                        rounded: score.fu.total,
-                    })
-                } else {
-                    None
-                };
-
-                JsonInterpretation {
-                    structure: format_structure_normalized(structure),
-                    yaku: yaku_list,
-                    dora: JsonDora {
-                        regular: yaku_result.regular_dora,
-                        ura: yaku_result.ura_dora,
-                        aka: yaku_result.aka_dora,
-                        total: yaku_result.dora_count,
-                    },
-                    han: score.han,
-                    fu: score.fu.total,
-                    score_level: if score.is_counted_yakuman {
-                        "Counted Yakuman".to_string()
-                    } else {
-                        score.score_level.name().to_string()
-                    },
-                    payment: JsonPayment {
-                        total: score.payment.total,
-                        from_discarder: score.payment.from_discarder,
-                        from_dealer: score.payment.from_dealer,
-                        from_non_dealer: score.payment.from_non_dealer,
-                    },
-                    fu_breakdown,
-                }
+                json_interpretation(structure, yaku_result, score, &context, &parsed.aka_tiles)
             })
             .collect();
 
@@ -738,9 +714,29 @@ fn main() {
         return;
     }
 
+    if args.csv {
+        let hand = format_hand_normalized(&parsed);
+        for &(structure, yaku_result, score) in &results_to_show {
+            println!(
+                "{}",
+                csv_line(&hand, structure, yaku_result, score, &context)
+            );
+        }
+        return;
+    }
+
     // Display results (human-readable)
     print_header(use_unicode);
 
+    if args.table {
+        println!();
+        for row in results_table_rows(&results_to_show, &parsed.aka_tiles) {
+            println!("   {}", row);
+        }
+        print_footer(use_unicode);
+        return;
+    }
+
     for (i, &(structure, yaku_result, score)) in results_to_show.iter().enumerate() {
         if i > 0 {
             println!("\n{}", "─".repeat(50));
@@ -750,7 +746,7 @@ fn main() {
             println!("\n📋 Interpretation {}", i + 1);
         }
 
-        print_hand(structure, use_unicode);
+        print_hand(structure, &parsed.aka_tiles, use_unicode);
         print_context(&context, &parsed, use_unicode);
         print_yaku(yaku_result, &context);
         print_score(score);
@@ -759,16 +755,245 @@ fn main() {
     print_footer(use_unicode);
 }
 
-fn parse_wind(s: &str) -> Result<Honor, String> {
-    match s.to_lowercase().as_str() {
-        "e" | "east" | "1" => Ok(Honor::East),
-        "s" | "south" | "2" => Ok(Honor::South),
-        "w" | "west" | "3" => Ok(Honor::West),
-        "n" | "north" | "4" => Ok(Honor::North),
-        _ => Err(format!("Invalid wind: {}. Use e/s/w/n", s)),
+/// Score every hand in `lines` with the default context and tally the results
+///
+/// Blank lines are ignored. Hands whose best interpretation has no yaku are not
+/// recorded. Returns the stats, the number of lines skipped because they could not
+/// be parsed or scored, and the number of hands with no yaku.
+fn hand_stats<'a>(lines: impl Iterator<Item = &'a str>) -> (YakuStats, usize, usize) {
+    let mut stats = YakuStats::new();
+    let mut skipped = 0;
+    let mut no_yaku = 0;
+
+    for line in lines.map(str::trim).filter(|l| !l.is_empty()) {
+        match score_line(line) {
+            Some(best) if best.has_yaku() => stats.record(&best.yaku, &best.score),
+            Some(_) => no_yaku += 1,
+            None => skipped += 1,
+        }
+    }
+
+    (stats, skipped, no_yaku)
+}
+
+/// Best interpretation of one hand as an East-seat win in the East round
+///
+/// Scored as ron unless the winning tile is marked for tsumo with `=`. Without a
+/// marked winning tile, every tile in the hand is tried.
+fn score_line(line: &str) -> Option<Interpretation> {
+    let (parsed, marked_win) = parse_hand_with_win(line).ok()?;
+    if parsed.called_melds.is_empty() {
+        validate_hand(&parsed.tiles).ok()?;
+    } else {
+        validate_hand_with_melds(&parsed).ok()?;
+    }
+
+    let counts = parsed.concealed_counts();
+    let all_tiles_counts = parsed.all_counts();
+    let called_melds: Vec<_> = parsed
+        .called_melds
+        .iter()
+        .map(|cm| cm.meld.clone())
+        .collect();
+
+    let structures = if called_melds.is_empty() {
+        decompose_hand(&counts)
+    } else {
+        decompose_hand_with_melds(&counts, &called_melds)
+    };
+
+    let win_type = match marked_win {
+        Some(m) if m.is_tsumo => WinType::Tsumo,
+        _ => WinType::Ron,
+    };
+    let mut context =
+        GameContext::new(win_type, Honor::East, Honor::East).with_aka(parsed.aka_count);
+    if called_melds.iter().any(|m| m.is_open()) {
+        context = context.open();
+    }
+    let winning_tiles: Vec<Tile> = match marked_win {
+        Some(m) => vec![m.tile],
+        None => parsed
+            .tiles
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect(),
+    };
+
+    winning_tiles
+        .into_iter()
+        .filter_map(|tile| {
+            let context = context.clone().with_winning_tile(tile);
+            rank_interpretations(&structures, &all_tiles_counts, &context)
+                .into_iter()
+                .next()
+        })
+        .max_by(compare_interpretations)
+}
+
+/// Print yaku frequencies and averages for the hands in `file`
+fn run_stats(file: &Path) {
+    let contents = match fs::read_to_string(file) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!(
+                "{} {}: {}",
+                "❌ Error reading".red().bold(),
+                file.display(),
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let (stats, skipped, no_yaku) = hand_stats(contents.lines());
+
+    println!("{}", "📊 Hand Statistics".yellow().bold());
+    println!("   Hands scored: {}", stats.hands.to_string().cyan());
+    if no_yaku > 0 {
+        println!("   No yaku: {}", no_yaku.to_string().yellow());
+    }
+    if skipped > 0 {
+        println!("   Skipped (invalid): {}", skipped.to_string().red());
+    }
+    println!("   Average han: {:.2}", stats.average_han());
+    println!("   Average fu: {:.2}", stats.average_fu());
+
+    println!("\n{}", "🏆 Yaku:".yellow().bold());
+    for (yaku, count) in stats.sorted_counts() {
+        println!("   {} {} ({})", "•".green(), yaku, count);
+    }
+}
+
+/// JSON form of one scored interpretation
+fn json_interpretation(
+    structure: &HandStructure,
+    yaku_result: &YakuResult,
+    score: &ScoringResult,
+    context: &GameContext,
+    aka_tiles: &[Tile],
+) -> JsonInterpretation {
+    let summary = ScoreSummary::from((structure, yaku_result, score, context));
+
+    let yaku_list: Vec<JsonYaku> = summary
+        .yaku
+        .iter()
+        .map(|(y, han)| JsonYaku {
+            name: y.to_string(),
+            han: *han,
+            yakuman: y.is_yakuman(),
+        })
+        .collect();
+
+    let fu = &summary.fu;
+    let fu_breakdown = if fu.total != 25
+        && fu.total != 20
+        && (fu.breakdown.raw_total > 20 || fu.breakdown.floored_to_open_minimum)
+    {
+        Some(JsonFuBreakdown {
+            base: 20,
+            menzen_ron: fu.breakdown.menzen_ron,
+            tsumo: fu.breakdown.tsumo,
+            melds: fu.breakdown.melds,
+            pair: fu.breakdown.pair,
+            wait: fu.breakdown.wait,
+            raw: fu.breakdown.raw_total,
+            rounded: fu.total,
+            floored_to_open_minimum: fu.breakdown.floored_to_open_minimum,
+        })
+    } else {
+        None
+    };
+
+    let wait = wait_type(structure, context);
+
+    JsonInterpretation {
+        structure: format_structure_normalized(&summary.structure, aka_tiles),
+        yaku: yaku_list,
+        dora: JsonDora {
+            regular: summary.dora.regular,
+            ura: summary.dora.ura,
+            aka: summary.dora.aka,
+            total: summary.dora.total(),
+        },
+        han: summary.total_han,
+        fu: fu.total,
+        wait_type: wait.map(|w| w.name().to_string()),
+        wait_fu: wait.map(|w| w.fu()),
+        score_level: summary.score_level_name().to_string(),
+        payment: JsonPayment {
+            total: summary.payment.total,
+            from_discarder: summary.payment.from_discarder,
+            from_dealer: summary.payment.from_dealer,
+            from_non_dealer: summary.payment.from_non_dealer,
+        },
+        fu_breakdown,
     }
 }
 
+/// One CSV row for an interpretation: `hand,han,fu,score_level,total_points,yaku`,
+/// with yaku names joined by semicolons
+fn csv_line(
+    hand: &str,
+    structure: &HandStructure,
+    yaku_result: &YakuResult,
+    score: &ScoringResult,
+    context: &GameContext,
+) -> String {
+    let summary = ScoreSummary::from((structure, yaku_result, score, context));
+    let yaku: Vec<String> = summary.yaku.iter().map(|(y, _)| y.to_string()).collect();
+    format!(
+        "{},{},{},{},{},{}",
+        hand,
+        summary.total_han,
+        summary.fu.total,
+        summary.score_level_name(),
+        summary.payment.total,
+        yaku.join(";")
+    )
+}
+
+/// Lay out interpretations as a fixed-width table: a header row, then one row
+/// per interpretation in the given (already sorted) order.
+fn results_table_rows(
+    results: &[(&HandStructure, &YakuResult, &ScoringResult)],
+    aka_tiles: &[Tile],
+) -> Vec<String> {
+    let structures: Vec<String> = results
+        .iter()
+        .map(|(structure, _, _)| format_structure_normalized(structure, aka_tiles))
+        .collect();
+    let width = structures
+        .iter()
+        .map(|s| s.len())
+        .chain(std::iter::once("Structure".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut rows = vec![format!(
+        "{:<width$}  {:>4}  {:>3}  {:>3}  {:>6}",
+        "Structure", "Yaku", "Han", "Fu", "Points"
+    )];
+    for (structure, (_, yaku_result, score)) in structures.iter().zip(results) {
+        rows.push(format!(
+            "{:<width$}  {:>4}  {:>3}  {:>3}  {:>6}",
+            structure,
+            yaku_result.yaku_list.len(),
+            score.han,
+            score.fu.total,
+            score.payment.total
+        ));
+    }
+    rows
+}
+
+/// Serialize a fully-resolved context so a run can be reproduced exactly
+fn context_json(context: &GameContext) -> String {
+    serde_json::to_string_pretty(context).unwrap()
+}
+
 /// Validate that riichi-dependent options are used with riichi,
 /// and that riichi is not used with open hands.
 /// Returns a list of warning messages for any invalid combinations.
@@ -810,62 +1035,16 @@ fn validate_riichi_dependencies(
 }
 
 fn parse_single_tile(s: &str) -> Result<Tile, String> {
-    let s = s.trim().to_lowercase();
-
-    // Check for honor tile letter notation first
-    // Winds: e, s, w, n (east, south, west, north)
-    // Dragons: wh (white), g (green), r (red)
-    match s.as_str() {
-        "e" | "east" => return Ok(Tile::honor(Honor::East)),
-        "s" | "south" => return Ok(Tile::honor(Honor::South)),
-        "w" | "west" => return Ok(Tile::honor(Honor::West)),
-        "n" | "north" => return Ok(Tile::honor(Honor::North)),
-        "wh" | "white" | "haku" => return Ok(Tile::honor(Honor::White)),
-        "g" | "green" | "hatsu" => return Ok(Tile::honor(Honor::Green)),
-        "r" | "red" | "chun" => return Ok(Tile::honor(Honor::Red)),
-        _ => {}
-    }
-
-    // Standard notation: digit + suit (e.g., "5m", "1z")
-    // Must be exactly 2 characters
-    if s.len() < 2 {
-        return Err(format!("Tile notation too short: {}", s));
-    }
-    if s.len() > 2 {
-        return Err(format!(
-            "Expected a single tile, got '{}'. Use -d/--dora for multiple tiles.",
-            s
-        ));
-    }
-
-    let value_char = s.chars().next().unwrap();
-    let suit_char = s.chars().last().unwrap();
-
-    let value = match value_char.to_digit(10) {
-        Some(v) if (1..=9).contains(&v) => v as u8,
-        Some(0) => 5, // Red five
-        _ => return Err(format!("Invalid tile value: {}", value_char)),
-    };
-
-    match suit_char {
-        'm' => Ok(Tile::suited(Suit::Man, value)),
-        'p' => Ok(Tile::suited(Suit::Pin, value)),
-        's' => Ok(Tile::suited(Suit::Sou, value)),
-        'z' => {
-            let honor = match value {
-                1 => Honor::East,
-                2 => Honor::South,
-                3 => Honor::West,
-                4 => Honor::North,
-                5 => Honor::White,
-                6 => Honor::Green,
-                7 => Honor::Red,
-                _ => return Err(format!("Invalid honor: {}z", value)),
-            };
-            Ok(Tile::honor(honor))
+    s.parse().map_err(|e: ParseError| {
+        if s.trim().len() > 2 && s.chars().any(|c| c.is_ascii_digit()) {
+            format!(
+                "Expected a single tile, got '{}'. Use -d/--dora for multiple tiles.",
+                s.trim()
+            )
+        } else {
+            e.to_string()
         }
-        _ => Err(format!("Invalid suit: {}", suit_char)),
-    }
+    })
 }
 
 /// Try to parse an honor tile from letter notation at the given position.
@@ -1013,9 +1192,9 @@ fn print_footer(use_unicode: bool) {
     }
 }
 
-fn print_hand(structure: &HandStructure, use_unicode: bool) {
+fn print_hand(structure: &HandStructure, aka_tiles: &[Tile], use_unicode: bool) {
     println!("\n{}", "📋 Hand Structure:".yellow().bold());
-    println!("   {}", format_structure(structure, use_unicode));
+    println!("   {}", format_structure(structure, use_unicode, aka_tiles));
 }
 
 fn print_context(context: &GameContext, parsed: &agari::parse::ParsedHand, use_unicode: bool) {
@@ -1114,14 +1293,8 @@ fn print_yaku(yaku_result: &agari::yaku::YakuResult, context: &GameContext) {
         return;
     }
 
-    for yaku in &yaku_result.yaku_list {
-        let han = if context.is_open {
-            yaku.han_open().unwrap_or(0)
-        } else {
-            yaku.han()
-        };
-
-        let name = yaku_name(yaku);
+    for (yaku, han) in &displayed_yaku(yaku_result, context) {
+        let name = yaku.to_string();
         let han_str = format!("({} han)", han);
 
         if yaku.is_yakuman() {
@@ -1241,7 +1414,10 @@ fn print_score(score: &ScoringResult) {
     }
 
     // Fu breakdown (only if interesting)
-    if score.fu.total != 25 && score.fu.total != 20 && score.fu.breakdown.raw_total > 20 {
+    if score.fu.total != 25
+        && score.fu.total != 20
+        && (score.fu.breakdown.raw_total > 20 || score.fu.breakdown.floored_to_open_minimum)
+    {
         println!("\n   {}:", "Fu breakdown".dimmed());
         println!("     {}: 20", "Base".dimmed());
         if score.fu.breakdown.menzen_ron > 0 {
@@ -1263,13 +1439,23 @@ fn print_score(score: &ScoringResult) {
         if score.fu.breakdown.wait > 0 {
             println!("     {}: +{}", "Wait".dimmed(), score.fu.breakdown.wait);
         }
-        println!(
-            "     {}: {} → {}: {}",
-            "Raw".dimmed(),
-            score.fu.breakdown.raw_total,
-            "Rounded".dimmed(),
-            score.fu.total
-        );
+        if score.fu.breakdown.floored_to_open_minimum {
+            println!(
+                "     {}: {} → {} {} fu minimum",
+                "Raw".dimmed(),
+                score.fu.breakdown.raw_total,
+                "raised to".dimmed(),
+                score.fu.total
+            );
+        } else {
+            println!(
+                "     {}: {} → {}: {}",
+                "Raw".dimmed(),
+                score.fu.breakdown.raw_total,
+                "Rounded".dimmed(),
+                score.fu.total
+            );
+        }
     }
 }
 
@@ -1277,6 +1463,7 @@ fn print_shanten(
     counts: &agari::parse::TileCounts,
     called_melds: u8,
     show_ukeire: bool,
+    show_grid: bool,
     use_unicode: bool,
     visible_counts: Option<&TileCounts>,
 ) {
@@ -1322,6 +1509,9 @@ fn print_shanten(
         ShantenType::Kokushi => "Kokushi (13 orphans)",
     };
     println!("   {}: {}", "Best shape".dimmed(), type_name);
+    if let Some(bottleneck) = &result.bottleneck {
+        println!("   {}: {}", "Bottleneck".dimmed(), bottleneck);
+    }
 
     // Ukeire (tile acceptance)
     if show_ukeire && result.shanten >= 0 {
@@ -1340,6 +1530,17 @@ fn print_shanten(
 
         if ukeire.tiles.is_empty() {
             println!("   {}", "No tiles improve this hand.".dimmed());
+        } else if show_grid {
+            println!(
+                "   {} tiles improve the hand ({} total):",
+                ukeire.tiles.len().to_string().bright_white().bold(),
+                ukeire.total_count.to_string().bright_white().bold()
+            );
+            println!();
+
+            for row in ukeire_grid_rows(&ukeire) {
+                println!("   {}", row);
+            }
         } else {
             println!(
                 "   {} tiles improve the hand ({} total):",
@@ -1373,6 +1574,26 @@ fn print_shanten(
     }
 }
 
+/// Lay out ukeire as a grid: a header row, then one row per suit and honors.
+/// Each cell holds the available count, or "." if the tile doesn't improve the hand.
+fn ukeire_grid_rows(ukeire: &UkeireResult) -> Vec<String> {
+    let tiles = all_tiles();
+    let cell = |tile: &Tile| match ukeire.tiles.iter().find(|ut| ut.tile == *tile) {
+        Some(ut) => format!("{:>3}", ut.available),
+        None => format!("{:>3}", "."),
+    };
+
+    let mut rows = vec![format!(
+        " {}",
+        (1..=9).map(|n| format!("{:>3}", n)).collect::<String>()
+    )];
+    for (label, range) in [("m", 0..9), ("p", 9..18), ("s", 18..27), ("z", 27..34)] {
+        let cells: String = tiles[range].iter().map(cell).collect();
+        rows.push(format!("{}{}", label, cells));
+    }
+    rows
+}
+
 fn print_shanten_json(
     counts: &agari::parse::TileCounts,
     called_melds: u8,
@@ -1421,73 +1642,17 @@ fn print_shanten_json(
         shanten: result.shanten,
         description: shanten_desc,
         best_type: type_name.to_string(),
+        bottleneck: result.bottleneck.clone(),
         ukeire: ukeire_data,
     };
 
     println!("{}", serde_json::to_string_pretty(&output).unwrap());
 }
 
-fn yaku_name(yaku: &Yaku) -> &'static str {
-    match yaku {
-        Yaku::Riichi => "Riichi",
-        Yaku::Ippatsu => "Ippatsu",
-        Yaku::MenzenTsumo => "Menzen Tsumo",
-        Yaku::Tanyao => "Tanyao (All Simples)",
-        Yaku::Pinfu => "Pinfu",
-        Yaku::Iipeikou => "Iipeikou (Pure Double Sequence)",
-        Yaku::Yakuhai(h) => match h {
-            Honor::East => "Yakuhai: East Wind",
-            Honor::South => "Yakuhai: South Wind",
-            Honor::West => "Yakuhai: West Wind",
-            Honor::North => "Yakuhai: North Wind",
-            Honor::White => "Yakuhai: White Dragon (Haku)",
-            Honor::Green => "Yakuhai: Green Dragon (Hatsu)",
-            Honor::Red => "Yakuhai: Red Dragon (Chun)",
-        },
-        Yaku::RinshanKaihou => "Rinshan Kaihou (After Kan)",
-        Yaku::Chankan => "Chankan (Robbing the Kan)",
-        Yaku::HaiteiRaoyue => "Haitei Raoyue (Last Tile Draw)",
-        Yaku::HouteiRaoyui => "Houtei Raoyui (Last Tile Discard)",
-        Yaku::DoubleRiichi => "Double Riichi",
-        Yaku::Toitoi => "Toitoi (All Triplets)",
-        Yaku::SanshokuDoujun => "Sanshoku Doujun (Mixed Triple Sequence)",
-        Yaku::SanshokuDoukou => "Sanshoku Doukou (Triple Triplets)",
-        Yaku::Ittsu => "Ittsu (Pure Straight)",
-        Yaku::Chiitoitsu => "Chiitoitsu (Seven Pairs)",
-        Yaku::Chanta => "Chanta (Outside Hand)",
-        Yaku::SanAnkou => "San Ankou (Three Concealed Triplets)",
-        Yaku::SanKantsu => "San Kantsu (Three Kans)",
-        Yaku::Honroutou => "Honroutou (All Terminals and Honors)",
-        Yaku::Shousangen => "Shousangen (Little Three Dragons)",
-        Yaku::Honitsu => "Honitsu (Half Flush)",
-        Yaku::Junchan => "Junchan (Terminals in All Groups)",
-        Yaku::Ryanpeikou => "Ryanpeikou (Twice Pure Double Sequence)",
-        Yaku::Chinitsu => "Chinitsu (Full Flush)",
-
-        // Yakuman
-        Yaku::Tenhou => "Tenhou (Heavenly Hand)",
-        Yaku::Chiihou => "Chiihou (Earthly Hand)",
-        Yaku::KokushiMusou => "Kokushi Musou (Thirteen Orphans)",
-        Yaku::Suuankou => "Suuankou (Four Concealed Triplets)",
-        Yaku::Daisangen => "Daisangen (Big Three Dragons)",
-        Yaku::Shousuushii => "Shousuushii (Little Four Winds)",
-        Yaku::Daisuushii => "Daisuushii (Big Four Winds)",
-        Yaku::Tsuuiisou => "Tsuuiisou (All Honors)",
-        Yaku::Chinroutou => "Chinroutou (All Terminals)",
-        Yaku::Ryuuiisou => "Ryuuiisou (All Green)",
-        Yaku::ChuurenPoutou => "Chuuren Poutou (Nine Gates)",
-        Yaku::SuuKantsu => "Suu Kantsu (Four Kans)",
-
-        // Double Yakuman
-        Yaku::Kokushi13Wait => "Kokushi Juusanmen (Kokushi Musou 13-wait)",
-        Yaku::SuuankouTanki => "Suuankou Tanki",
-        Yaku::JunseiChuurenPoutou => "Junsei Chuuren Poutou",
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use agari::tile::Suit;
 
     // ===== parse_single_tile tests =====
 
@@ -1803,4 +1968,162 @@ mod tests {
         let warnings = validate_riichi_dependencies(false, false, false, false, true);
         assert!(warnings.is_empty());
     }
+
+    // ===== ukeire grid tests =====
+
+    #[test]
+    fn test_ukeire_grid_cells() {
+        let counts = to_counts(&agari::parse::parse_hand("123456m456p11z45s").unwrap());
+        let ukeire = calculate_ukeire_with_melds(&counts, 0);
+        let rows = ukeire_grid_rows(&ukeire);
+
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[0], "   1  2  3  4  5  6  7  8  9");
+        assert_eq!(rows[1], "m  .  .  .  .  .  .  .  .  .");
+        assert_eq!(rows[2], "p  .  .  .  .  .  .  .  .  .");
+        assert_eq!(rows[3], "s  .  .  4  .  .  4  .  .  .");
+        assert_eq!(rows[4], "z  .  .  .  .  .  .  .");
+    }
+
+    // ===== results table tests =====
+
+    #[test]
+    fn test_results_table_one_row_per_interpretation() {
+        // Three identical sequences or three triplets
+        let counts = to_counts(&agari::parse::parse_hand("111222333m456p99s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Pin, 6));
+        let scored: Vec<_> = decompose_hand(&counts)
+            .into_iter()
+            .map(|s| {
+                let yaku_result = detect_yaku_with_context(&s, &counts, &context);
+                let score = calculate_score(&s, &yaku_result, &context);
+                (s, yaku_result, score)
+            })
+            .collect();
+        assert!(scored.len() > 1);
+
+        let results: Vec<_> = scored.iter().map(|(s, y, sc)| (s, y, sc)).collect();
+        let rows = results_table_rows(&results, &[]);
+
+        assert_eq!(rows.len(), results.len() + 1);
+        assert!(rows[0].starts_with("Structure"));
+        assert!(rows[0].ends_with("Points"));
+        for (row, (_, _, score)) in rows[1..].iter().zip(&results) {
+            assert!(row.ends_with(&score.payment.total.to_string()));
+            assert_eq!(row.len(), rows[0].len());
+        }
+    }
+
+    // ===== JSON output tests =====
+
+    #[test]
+    fn test_json_interpretation_has_wait_type() {
+        // Kanchan on 3m (middle of 234m)
+        let counts = to_counts(&agari::parse::parse_hand("234m456p789s567m55s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .riichi()
+            .with_winning_tile(Tile::suited(Suit::Man, 3));
+        let structures = decompose_hand(&counts);
+        let yaku_result = detect_yaku_with_context(&structures[0], &counts, &context);
+        let score = calculate_score(&structures[0], &yaku_result, &context);
+
+        let json = serde_json::to_string(&json_interpretation(
+            &structures[0],
+            &yaku_result,
+            &score,
+            &context,
+            &[],
+        ))
+        .unwrap();
+
+        assert!(json.contains(r#""wait_type":"kanchan""#), "{json}");
+        assert!(json.contains(r#""wait_fu":2"#), "{json}");
+    }
+
+    // ===== CSV output tests =====
+
+    #[test]
+    fn test_csv_line_columns_and_total() {
+        // Riichi + pinfu + tanyao + iipeikou, ron on 2m: 4 han 30 fu = 7700
+        let counts = to_counts(&agari::parse::parse_hand("223344m345p678s55p").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .riichi()
+            .with_winning_tile(Tile::suited(Suit::Man, 2));
+        let structures = decompose_hand(&counts);
+        assert_eq!(structures.len(), 1);
+        let yaku_result = detect_yaku_with_context(&structures[0], &counts, &context);
+        let score = calculate_score(&structures[0], &yaku_result, &context);
+
+        let line = csv_line(
+            "223344m345p678s55p",
+            &structures[0],
+            &yaku_result,
+            &score,
+            &context,
+        );
+        let columns: Vec<&str> = line.split(',').collect();
+
+        assert_eq!(columns.len(), 6);
+        assert_eq!(columns[0], "223344m345p678s55p");
+        assert_eq!(columns[1], "4");
+        assert_eq!(columns[2], "30");
+        assert_eq!(columns[4], "7700");
+        assert_eq!(columns[5].split(';').count(), 4);
+    }
+
+    // ===== context dump tests =====
+
+    #[test]
+    fn test_context_json_contains_dora_and_win_type() {
+        let dora = parse_tile_list("1m,e").unwrap();
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_dora(dora.clone())
+            .riichi();
+
+        let json = context_json(&context);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["win_type"], "Tsumo");
+        assert_eq!(value["is_riichi"], true);
+        assert_eq!(
+            value["dora_indicators"],
+            serde_json::to_value(&dora).unwrap()
+        );
+
+        // Round-trips back to the same context
+        let restored: GameContext = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, context);
+    }
+
+    // ===== stats subcommand tests =====
+
+    #[test]
+    fn test_stats_accepts_no_color_after_file() {
+        let args = Args::try_parse_from(["agari", "stats", "hands.txt", "--no-color"]).unwrap();
+
+        assert!(args.no_color);
+        assert!(matches!(args.command, Some(Command::Stats { .. })));
+    }
+
+    #[test]
+    fn test_hand_stats_tallies_yaku() {
+        use agari::yaku::Yaku;
+
+        let hands = "234567m234p345s88s\n\
+                     not a hand\n\
+                     \n\
+                     123m456p789s555z22m\n\
+                     234567m234p345s55z";
+        let (stats, skipped, no_yaku) = hand_stats(hands.lines());
+
+        // The haku-pair hand has no yaku on any winning tile and isn't recorded
+        assert_eq!(stats.hands, 2);
+        assert_eq!(skipped, 1);
+        assert_eq!(no_yaku, 1);
+        assert_eq!(stats.count(Yaku::Tanyao), 1);
+        assert_eq!(stats.count(Yaku::Pinfu), 1);
+        assert_eq!(stats.count(Yaku::Yakuhai(Honor::White)), 1);
+        assert_eq!(stats.yaku_counts.values().sum::<usize>(), 3);
+    }
 }
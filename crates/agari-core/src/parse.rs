@@ -1,4 +1,5 @@
 use crate::hand::{KanType, Meld};
+pub use crate::tile::ParseError;
 use crate::tile::{Honor, Suit, Tile};
 use std::collections::HashMap;
 
@@ -16,9 +17,44 @@ pub struct CalledMeld {
 pub struct ParsedHand {
     pub tiles: Vec<Tile>,              // Tiles in hand (not in called melds)
     pub aka_count: u8,                 // Number of red fives (0m, 0p, 0s)
+    pub aka_tiles: Vec<Tile>,          // The five written as each red five (e.g., 5p for 0p)
     pub called_melds: Vec<CalledMeld>, // Kans and other called melds
 }
 
+impl ParsedHand {
+    /// Counts of the concealed tiles only (the part of the hand that gets decomposed)
+    pub fn concealed_counts(&self) -> TileCounts {
+        to_counts(&self.tiles)
+    }
+
+    /// Counts of the tiles in called melds only
+    pub fn meld_counts(&self) -> TileCounts {
+        let meld_tiles: Vec<Tile> = self
+            .called_melds
+            .iter()
+            .flat_map(|called| called.tiles.iter().copied())
+            .collect();
+        to_counts(&meld_tiles)
+    }
+
+    /// Counts of every tile in the hand, concealed and called (used for dora and
+    /// tile supply checks)
+    pub fn all_counts(&self) -> TileCounts {
+        let mut counts = self.concealed_counts();
+        for (tile, count) in self.meld_counts() {
+            *counts.entry(tile).or_insert(0) += count;
+        }
+        counts
+    }
+}
+
+/// Winning tile marked inline in a hand string with `+` (ron) or `=` (tsumo)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WinMarker {
+    pub tile: Tile,
+    pub is_tsumo: bool,
+}
+
 /// Parse a hand string into tiles.
 /// Red fives use '0' notation: 0m = red 5m, 0p = red 5p, 0s = red 5s
 pub fn parse_hand(input: &str) -> Result<Vec<Tile>, String> {
@@ -72,6 +108,7 @@ fn try_parse_honor_letter(chars: &[char], pos: usize) -> Option<(Honor, usize)>
 pub fn parse_hand_with_aka(input: &str) -> Result<ParsedHand, String> {
     let mut tiles = Vec::new();
     let mut aka_count = 0u8;
+    let mut aka_tiles = Vec::new();
     let mut called_melds = Vec::new();
     // Store (digit, is_red) pairs
     let mut pending: Vec<(u8, bool)> = Vec::new();
@@ -102,6 +139,11 @@ pub fn parse_hand_with_aka(input: &str) -> Result<ParsedHand, String> {
                 let meld_str: String = chars[start..end].iter().collect();
                 let (meld, meld_tiles, meld_aka) = parse_meld(&meld_str, is_closed)?;
 
+                if let Some(five) = meld_tiles.iter().find(|t| t.value() == Some(5)) {
+                    for _ in 0..meld_aka {
+                        aka_tiles.push(*five);
+                    }
+                }
                 called_melds.push(CalledMeld {
                     meld,
                     tiles: meld_tiles,
@@ -134,6 +176,7 @@ pub fn parse_hand_with_aka(input: &str) -> Result<ParsedHand, String> {
                     tiles.push(Tile::suited(Suit::Man, n));
                     if is_red {
                         aka_count += 1;
+                        aka_tiles.push(Tile::suited(Suit::Man, 5));
                     }
                 }
                 pending.clear();
@@ -143,6 +186,7 @@ pub fn parse_hand_with_aka(input: &str) -> Result<ParsedHand, String> {
                     tiles.push(Tile::suited(Suit::Pin, n));
                     if is_red {
                         aka_count += 1;
+                        aka_tiles.push(Tile::suited(Suit::Pin, 5));
                     }
                 }
                 pending.clear();
@@ -152,6 +196,7 @@ pub fn parse_hand_with_aka(input: &str) -> Result<ParsedHand, String> {
                     tiles.push(Tile::suited(Suit::Sou, n));
                     if is_red {
                         aka_count += 1;
+                        aka_tiles.push(Tile::suited(Suit::Sou, 5));
                     }
                 }
                 pending.clear();
@@ -209,10 +254,42 @@ pub fn parse_hand_with_aka(input: &str) -> Result<ParsedHand, String> {
     Ok(ParsedHand {
         tiles,
         aka_count,
+        aka_tiles,
         called_melds,
     })
 }
 
+/// Parse a hand string whose winning tile is marked inline
+///
+/// A `+` before the tile marks a ron and a `=` marks a tsumo. The marked tile is
+/// still part of the hand: `123m456p789s234m5+5s` is the hand `123m456p789s234m55s`
+/// won by ron on 5s. The marker must come before the last tile, so `...=5s` at the
+/// end works too. Returns `None` for the win when there is no marker.
+pub fn parse_hand_with_win(input: &str) -> Result<(ParsedHand, Option<WinMarker>), ParseError> {
+    let Some(pos) = input.find(['+', '=']) else {
+        let parsed = parse_hand_with_aka(input).map_err(ParseError::InvalidHand)?;
+        return Ok((parsed, None));
+    };
+    let is_tsumo = input[pos..].starts_with('=');
+    let (hand, win) = (&input[..pos], &input[pos + 1..]);
+
+    let winning = parse_hand_with_aka(win)
+        .ok()
+        .filter(|p| p.tiles.len() == 1 && p.called_melds.is_empty())
+        .map(|p| p.tiles[0])
+        .ok_or_else(|| ParseError::InvalidTile(win.to_string()))?;
+
+    let parsed =
+        parse_hand_with_aka(&format!("{}{}", hand, win)).map_err(ParseError::InvalidHand)?;
+    Ok((
+        parsed,
+        Some(WinMarker {
+            tile: winning,
+            is_tsumo,
+        }),
+    ))
+}
+
 /// Parse a meld string (contents inside brackets)
 /// Returns (Meld, tiles, aka_count)
 /// Supports both numeric notation (e.g., "111z") and letter notation for honors (e.g., "eee")
@@ -387,6 +464,10 @@ fn parse_meld(meld_str: &str, is_closed: bool) -> Result<(Meld, Vec<Tile>, u8),
     Ok((meld, tiles, aka_count))
 }
 
+/// Count tiles by kind
+///
+/// Red fives are parsed as plain fives, so counts never distinguish them;
+/// akadora are tracked separately in `ParsedHand`.
 pub fn to_counts(tiles: &[Tile]) -> TileCounts {
     let mut counts = HashMap::new();
     for &tile in tiles {
@@ -395,6 +476,38 @@ pub fn to_counts(tiles: &[Tile]) -> TileCounts {
     counts
 }
 
+/// Number of tiles in each yaku-relevant category
+///
+/// Simples, terminals and honors partition the hand; greens (23468s and 6z)
+/// overlap with them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CategoryCounts {
+    pub simples: u8,
+    pub terminals: u8,
+    pub honors: u8,
+    pub greens: u8,
+}
+
+/// Count the simples, terminals, honors and green tiles in `counts`
+pub fn tile_category_counts(counts: &TileCounts) -> CategoryCounts {
+    let mut categories = CategoryCounts::default();
+    for (tile, &count) in counts {
+        if tile.is_simple() {
+            categories.simples += count;
+        }
+        if tile.is_terminal() {
+            categories.terminals += count;
+        }
+        if tile.is_honor() {
+            categories.honors += count;
+        }
+        if tile.is_green() {
+            categories.greens += count;
+        }
+    }
+    categories
+}
+
 /// Validate a hand for scoring (must be exactly 14 tiles, with kans counting as 3)
 pub fn validate_hand(tiles: &[Tile]) -> Result<(), String> {
     if tiles.len() != 14 {
@@ -412,19 +525,40 @@ pub fn validate_hand(tiles: &[Tile]) -> Result<(), String> {
 }
 
 /// Validate a hand with called melds
-/// Each kan adds 1 extra tile (4 tiles instead of 3), so:
+///
+/// Each called meld fills one of the four sets, so the concealed tiles must make
+/// up the rest plus the pair: `3 × (4 - melds) + 2`. A kan's fourth tile is the
+/// extra wall draw, so each kan adds 1 tile to the total:
 /// - 0 kans: 14 tiles
 /// - 1 kan: 15 tiles
 /// - 2 kans: 16 tiles
 /// - 3 kans: 17 tiles
 /// - 4 kans: 18 tiles
 pub fn validate_hand_with_melds(parsed: &ParsedHand) -> Result<(), String> {
+    let meld_count = parsed.called_melds.len();
+    if meld_count > 4 {
+        return Err(format!(
+            "Hand can have at most 4 called melds, got {}",
+            meld_count
+        ));
+    }
+
     let kan_count = parsed
         .called_melds
         .iter()
         .filter(|m| matches!(m.meld, Meld::Kan(_, _)))
         .count();
 
+    let expected_concealed = 3 * (4 - meld_count) + 2;
+    if parsed.tiles.len() != expected_concealed {
+        return Err(format!(
+            "Hand with {} called meld(s) must have {} concealed tiles, got {}",
+            meld_count,
+            expected_concealed,
+            parsed.tiles.len()
+        ));
+    }
+
     let total_tiles = parsed.tiles.len()
         + parsed
             .called_melds
@@ -442,12 +576,7 @@ pub fn validate_hand_with_melds(parsed: &ParsedHand) -> Result<(), String> {
     }
 
     // Check that no tile appears more than 4 times
-    let mut all_tiles = parsed.tiles.clone();
-    for called in &parsed.called_melds {
-        all_tiles.extend(&called.tiles);
-    }
-
-    let counts = to_counts(&all_tiles);
+    let counts = parsed.all_counts();
     for (tile, count) in &counts {
         if *count > 4 {
             return Err(format!("Tile {:?} appears {} times (max 4)", tile, count));
@@ -457,6 +586,26 @@ pub fn validate_hand_with_melds(parsed: &ParsedHand) -> Result<(), String> {
     Ok(())
 }
 
+/// Validate that dora indicators don't need more copies of a tile than exist
+///
+/// Indicators are physical tiles too, so hand, called meld and indicator copies
+/// of any tile together may not exceed 4.
+pub fn validate_indicator_supply(parsed: &ParsedHand, indicators: &[Tile]) -> Result<(), String> {
+    let mut counts = parsed.all_counts();
+    for &tile in indicators {
+        *counts.entry(tile).or_insert(0) += 1;
+    }
+    let mut over: Vec<_> = counts.iter().filter(|&(_, &count)| count > 4).collect();
+    over.sort();
+    match over.first() {
+        Some((tile, count)) => Err(format!(
+            "Tile {} appears {} times counting dora indicators (max 4)",
+            tile, count
+        )),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -505,6 +654,76 @@ mod tests {
         assert!(validate_hand(&tiles).is_err());
     }
 
+    #[test]
+    fn tile_category_counts_mixed_hand() {
+        let counts = to_counts(&parse_hand("19m234s567p66z11z99s").unwrap());
+        assert_eq!(
+            tile_category_counts(&counts),
+            CategoryCounts {
+                simples: 6,
+                terminals: 4,
+                honors: 4,
+                greens: 5,
+            }
+        );
+    }
+
+    // ===== Winning Tile Marker Tests =====
+
+    #[test]
+    fn win_marker_inline() {
+        let (parsed, winning) = parse_hand_with_win("123m456p789s234m5+5s").unwrap();
+        assert_eq!(parsed.tiles.len(), 14);
+        assert_eq!(
+            winning,
+            Some(WinMarker {
+                tile: Tile::suited(Suit::Sou, 5),
+                is_tsumo: false
+            })
+        );
+    }
+
+    #[test]
+    fn win_marker_trailing() {
+        let (parsed, winning) = parse_hand_with_win("123m456p789s234m5s+5s").unwrap();
+        assert_eq!(parsed.tiles.len(), 14);
+        assert_eq!(
+            winning,
+            Some(WinMarker {
+                tile: Tile::suited(Suit::Sou, 5),
+                is_tsumo: false
+            })
+        );
+    }
+
+    #[test]
+    fn win_marker_equals_is_tsumo() {
+        let (parsed, winning) = parse_hand_with_win("123m456p789s234m5s=5s").unwrap();
+        assert_eq!(parsed.tiles.len(), 14);
+        assert_eq!(
+            winning,
+            Some(WinMarker {
+                tile: Tile::suited(Suit::Sou, 5),
+                is_tsumo: true
+            })
+        );
+    }
+
+    #[test]
+    fn win_marker_absent() {
+        let (parsed, winning) = parse_hand_with_win("123m456p789s234m55s").unwrap();
+        assert_eq!(parsed.tiles.len(), 14);
+        assert_eq!(winning, None);
+    }
+
+    #[test]
+    fn win_marker_needs_single_tile() {
+        assert!(matches!(
+            parse_hand_with_win("123m456p789s234m+55s"),
+            Err(ParseError::InvalidTile(_))
+        ));
+    }
+
     // ===== Red Five (Akadora) Tests =====
 
     #[test]
@@ -525,6 +744,24 @@ mod tests {
         assert_eq!(result.aka_count, 3);
     }
 
+    #[test]
+    fn red_five_counts_as_plain_five() {
+        let red = to_counts(&parse_hand("340m").unwrap());
+        let plain = to_counts(&parse_hand("345m").unwrap());
+        assert_eq!(red, plain);
+        assert_eq!(red[&Tile::suited(Suit::Man, 5)], 1);
+    }
+
+    #[test]
+    fn parse_red_five_tracks_aka_tiles() {
+        let result = parse_hand_with_aka("0p55m(055s)").unwrap();
+        assert_eq!(
+            result.aka_tiles,
+            vec![Tile::suited(Suit::Pin, 5), Tile::suited(Suit::Sou, 5)]
+        );
+        assert_eq!(result.aka_count as usize, result.aka_tiles.len());
+    }
+
     #[test]
     fn parse_mixed_red_and_regular_fives() {
         // Hand with both red 5m and regular 5m
@@ -652,6 +889,20 @@ mod tests {
         assert!(validate_hand_with_melds(&result).is_ok());
     }
 
+    #[test]
+    fn validate_hand_rejects_concealed_miscount() {
+        // One kan: 15 raw tiles, but only 10 concealed where 11 are needed
+        let result = parse_hand_with_aka("[1111m]222333m555p1z").unwrap();
+        assert_eq!(
+            validate_hand_with_melds(&result),
+            Err("Hand with 1 called meld(s) must have 11 concealed tiles, got 10".to_string())
+        );
+
+        // 15 tiles but no kan to account for the extra one
+        let result = parse_hand_with_aka("(123m)456m789p111z22z5s").unwrap();
+        assert!(validate_hand_with_melds(&result).is_err());
+    }
+
     #[test]
     fn validate_hand_with_two_kans() {
         // [1111m] [2222p] 345s 678s 11z = 16 tiles (14 + 2 for 2 kans)
@@ -659,6 +910,22 @@ mod tests {
         assert!(validate_hand_with_melds(&result).is_ok());
     }
 
+    #[test]
+    fn indicator_supply_within_limit() {
+        let parsed = parse_hand_with_aka("123m456p789s(555z)11z").unwrap();
+        let indicators = parse_hand("5z1z").unwrap();
+        assert!(validate_indicator_supply(&parsed, &indicators).is_ok());
+    }
+
+    #[test]
+    fn indicator_supply_over_copied() {
+        // Three 5z in the pon plus two as indicators = 5 copies
+        let parsed = parse_hand_with_aka("123m456p789s(555z)11z").unwrap();
+        let indicators = parse_hand("5z5z").unwrap();
+        let err = validate_indicator_supply(&parsed, &indicators).unwrap_err();
+        assert!(err.contains("5 times"));
+    }
+
     #[test]
     fn invalid_kan_different_tiles() {
         let result = parse_hand_with_aka("[1234m]");
@@ -671,6 +938,24 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn concealed_and_meld_counts_split_open_pon() {
+        let parsed = parse_hand_with_aka("(555z)123m456p789s11s").unwrap();
+        let haku = Tile::honor(Honor::White);
+
+        let concealed = parsed.concealed_counts();
+        assert_eq!(concealed.values().sum::<u8>(), 11);
+        assert_eq!(concealed.get(&haku), None);
+
+        let melds = parsed.meld_counts();
+        assert_eq!(melds, HashMap::from([(haku, 3)]));
+
+        let all = parsed.all_counts();
+        assert_eq!(all.values().sum::<u8>(), 14);
+        assert_eq!(all[&haku], 3);
+        assert_eq!(all[&Tile::suited(Suit::Sou, 1)], 2);
+    }
+
     // ===== Honor Letter Notation Tests =====
 
     #[test]
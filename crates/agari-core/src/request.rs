@@ -0,0 +1,497 @@
+//! Score requests shared by the CLI and WASM frontends
+//!
+//! A [`ScoreRequest`] carries a hand and its win conditions as plain strings and
+//! flags. [`build_context`] turns it into a [`GameContext`], validating winds,
+//! tiles and flag combinations in one place so every frontend agrees.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::context::{GameContext, SituationalFlags, WinType};
+use crate::parse::{ParseError, ParsedHand, parse_hand_with_win, validate_indicator_supply};
+use crate::tile::{Honor, Tile};
+
+/// Input for scoring a hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreRequest {
+    /// Hand string in Agari notation (e.g., "123m456p789s11122z")
+    pub hand: String,
+    /// Winning tile (optional, will be inferred if not provided)
+    pub winning_tile: Option<String>,
+    /// Whether the win was by self-draw (tsumo)
+    pub is_tsumo: bool,
+    /// Whether riichi was declared
+    pub is_riichi: bool,
+    /// Whether double riichi was declared
+    pub is_double_riichi: bool,
+    /// Whether ippatsu (win within one turn of riichi)
+    pub is_ippatsu: bool,
+    /// Round wind: "east", "south", "west", "north"
+    pub round_wind: String,
+    /// Seat wind: "east", "south", "west", "north"
+    pub seat_wind: String,
+    /// Dora indicator tiles (e.g., ["1m", "5z"])
+    pub dora_indicators: Vec<String>,
+    /// Ura dora indicator tiles
+    pub ura_dora_indicators: Vec<String>,
+    /// Whether won on the last tile (haitei/houtei)
+    pub is_last_tile: bool,
+    /// Whether won on kan replacement tile (rinshan)
+    pub is_rinshan: bool,
+    /// Whether ron on another player's added kan (chankan)
+    pub is_chankan: bool,
+    /// The tile robbed from the added kan; must be the winning tile
+    #[serde(default)]
+    pub chankan_tile: Option<String>,
+    /// Whether tenhou (dealer first draw win)
+    pub is_tenhou: bool,
+    /// Whether chiihou (non-dealer first draw win)
+    pub is_chiihou: bool,
+    /// Treat the hand as open even without open called melds
+    #[serde(default)]
+    pub is_open: bool,
+    /// Whether to infer the winning tile when none is provided (default: true).
+    /// When false, the hand is scored without a winning tile (no pinfu or wait fu).
+    #[serde(default = "default_true")]
+    pub infer_winning_tile: bool,
+    /// The player's own discards (e.g., ["4m", "9p"]); used to reject furiten ron
+    #[serde(default)]
+    pub discards: Vec<String>,
+    /// Repeat counters (honba) on the table
+    #[serde(default)]
+    pub honba: u8,
+    /// Riichi deposits (kyotaku) on the table, collected by the winner
+    #[serde(default)]
+    pub riichi_sticks: u8,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Reasons a score request can fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScoreError {
+    /// Hand or tile notation could not be parsed
+    Parse(ParseError),
+    /// Round or seat wind is not a valid wind; `field` is "round" or "seat"
+    InvalidWind { field: &'static str, value: String },
+    /// The tiles do not form a complete hand
+    NoStructure,
+    /// The hand is complete but has no yaku
+    NoYaku,
+    /// Situational flags contradict each other or the hand
+    ContradictoryFlags(String),
+    /// The declared winning tile is not part of the hand
+    WinningTileNotInHand(Tile),
+    /// Ron on a wait the player has discarded themselves
+    Furiten(Tile),
+    /// Hand, melds and dora indicators use more than 4 copies of a tile
+    TooManyCopies(String),
+}
+
+impl ScoreError {
+    /// Stable error code for the frontend to branch on
+    pub fn code(&self) -> &'static str {
+        match self {
+            ScoreError::Parse(_) => "parse_error",
+            ScoreError::InvalidWind { .. } => "invalid_wind",
+            ScoreError::NoStructure => "no_structure",
+            ScoreError::NoYaku => "no_yaku",
+            ScoreError::ContradictoryFlags(_) => "contradictory_flags",
+            ScoreError::WinningTileNotInHand(_) => "winning_tile_not_in_hand",
+            ScoreError::Furiten(_) => "furiten",
+            ScoreError::TooManyCopies(_) => "too_many_copies",
+        }
+    }
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoreError::Parse(e) => write!(f, "{}", e),
+            ScoreError::InvalidWind { field, value } => {
+                write!(f, "Invalid {} wind: {}", field, value)
+            }
+            ScoreError::NoStructure => write!(f, "No valid hand structure found"),
+            ScoreError::NoYaku => write!(f, "No valid yaku found for this hand"),
+            ScoreError::ContradictoryFlags(msg) => write!(f, "{}", msg),
+            ScoreError::WinningTileNotInHand(tile) => {
+                write!(f, "Winning tile {} is not in the hand", tile)
+            }
+            ScoreError::Furiten(tile) => {
+                write!(f, "Cannot ron on {}: the hand is furiten", tile)
+            }
+            ScoreError::TooManyCopies(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ScoreError {}
+
+impl From<ParseError> for ScoreError {
+    fn from(e: ParseError) -> Self {
+        ScoreError::Parse(e)
+    }
+}
+
+/// Parse a wind name: "east"/"e"/"1"/"1z" and so on, case-insensitive
+pub fn parse_wind(s: &str) -> Option<Honor> {
+    match s.to_lowercase().as_str() {
+        "east" | "e" | "1" | "1z" => Some(Honor::East),
+        "south" | "s" | "2" | "2z" => Some(Honor::South),
+        "west" | "w" | "3" | "3z" => Some(Honor::West),
+        "north" | "n" | "4" | "4z" => Some(Honor::North),
+        _ => None,
+    }
+}
+
+/// Build the game context for a request
+///
+/// Parses the hand (an inline `+`/`=` winning tile marker sets the winning tile and
+/// win type, and is rejected if `winning_tile` names another tile or `is_tsumo`
+/// contradicts a `+` ron marker), winds and dora indicators, and rejects tenhou or
+/// chiihou with calls, a winning tile that isn't in the hand, and indicators that
+/// would need a fifth copy of a tile, and a chankan tile that isn't the winning
+/// tile or comes without the chankan flag. Discards are parsed to check them but are
+/// otherwise left to the caller, as is winning tile inference. Fails with the
+/// first problem [`validate_request`] would report.
+pub fn build_context(req: &ScoreRequest) -> Result<GameContext, ScoreError> {
+    check_request(req)
+        .map(|(_, context)| context)
+        .map_err(|mut errors| errors.swap_remove(0))
+}
+
+/// Parse the hand of a request, with any inline winning tile marker stripped
+///
+/// The marker itself is applied by [`build_context`]; this only checks the hand.
+pub fn parse_request_hand(req: &ScoreRequest) -> Result<ParsedHand, ScoreError> {
+    parse_hand_with_win(&req.hand)
+        .map(|(parsed, _)| parsed)
+        .map_err(ScoreError::Parse)
+}
+
+/// Every problem [`build_context`] could report for a request, without scoring
+///
+/// Runs the same checks but keeps going after a failure. Checks that need the
+/// parsed hand are skipped when the hand doesn't parse. Empty when the request is
+/// valid.
+pub fn validate_request(req: &ScoreRequest) -> Vec<ScoreError> {
+    check_request(req).err().unwrap_or_default()
+}
+
+/// Shared checks behind [`build_context`] and [`validate_request`]
+///
+/// Returns the hand and context only when every check passes, and otherwise every
+/// error found, in request field order (never an empty list).
+fn check_request(req: &ScoreRequest) -> Result<(ParsedHand, GameContext), Vec<ScoreError>> {
+    let mut errors = Vec::new();
+
+    let parsed = parse_hand_with_win(&req.hand)
+        .map_err(|e| errors.push(ScoreError::Parse(e)))
+        .ok();
+
+    let round_wind = parse_wind(&req.round_wind);
+    if round_wind.is_none() {
+        errors.push(ScoreError::InvalidWind {
+            field: "round",
+            value: req.round_wind.clone(),
+        });
+    }
+    let seat_wind = parse_wind(&req.seat_wind);
+    if seat_wind.is_none() {
+        errors.push(ScoreError::InvalidWind {
+            field: "seat",
+            value: req.seat_wind.clone(),
+        });
+    }
+
+    // Tenhou/Chiihou are won on the first draw, so no call (not even a closed kan) can have occurred
+    if let Some((parsed, _)) = &parsed
+        && (req.is_tenhou || req.is_chiihou)
+        && !parsed.called_melds.is_empty()
+    {
+        let name = if req.is_tenhou { "Tenhou" } else { "Chiihou" };
+        errors.push(ScoreError::ContradictoryFlags(format!(
+            "{} cannot be declared with called melds",
+            name
+        )));
+    }
+    if req.is_tenhou && req.is_chiihou {
+        errors.push(ScoreError::ContradictoryFlags(
+            "Tenhou and Chiihou cannot both be declared".to_string(),
+        ));
+    }
+
+    let dora_indicators = parse_tiles(&req.dora_indicators, &mut errors);
+    let ura_dora_indicators = parse_tiles(&req.ura_dora_indicators, &mut errors);
+    parse_tiles(&req.discards, &mut errors);
+
+    let winning_tile = req
+        .winning_tile
+        .as_ref()
+        .and_then(|wt| wt.parse::<Tile>().map_err(|e| errors.push(e.into())).ok());
+    let chankan_tile = req
+        .chankan_tile
+        .as_ref()
+        .and_then(|t| t.parse::<Tile>().map_err(|e| errors.push(e.into())).ok());
+
+    let Some((parsed, marked_win)) = parsed else {
+        return Err(errors);
+    };
+
+    // Indicators are physical tiles: hand + melds + indicators can't exceed 4 copies
+    let all_indicators: Vec<Tile> = dora_indicators
+        .iter()
+        .chain(&ura_dora_indicators)
+        .copied()
+        .collect();
+    if let Err(e) = validate_indicator_supply(&parsed, &all_indicators) {
+        errors.push(ScoreError::TooManyCopies(e));
+    }
+
+    // An inline marker and the request fields must agree on the winning tile and win type
+    if let (Some(tile), Some(marked)) = (winning_tile, marked_win)
+        && tile != marked.tile
+    {
+        errors.push(ScoreError::ContradictoryFlags(format!(
+            "winning tile {} does not match the {} marked in the hand",
+            tile, marked.tile
+        )));
+    }
+    if req.is_tsumo && marked_win.is_some_and(|m| !m.is_tsumo) {
+        errors.push(ScoreError::ContradictoryFlags(
+            "hand marks a ron win but the request is a tsumo".to_string(),
+        ));
+    }
+
+    let winning_tile = match &req.winning_tile {
+        Some(_) => winning_tile,
+        None => marked_win.map(|m| m.tile),
+    };
+    if let Some(tile) = winning_tile
+        && !parsed.tiles.contains(&tile)
+    {
+        errors.push(ScoreError::WinningTileNotInHand(tile));
+    }
+
+    let (Some(round_wind), Some(seat_wind)) = (round_wind, seat_wind) else {
+        return Err(errors);
+    };
+
+    let win_type = if marked_win.map_or(req.is_tsumo, |m| m.is_tsumo) {
+        WinType::Tsumo
+    } else {
+        WinType::Ron
+    };
+
+    let mut context = GameContext::new(win_type, round_wind, seat_wind)
+        .with_dora(dora_indicators)
+        .with_ura_dora(ura_dora_indicators)
+        .with_aka(parsed.aka_count)
+        .with_honba(req.honba)
+        .with_riichi_sticks(req.riichi_sticks)
+        .with_flags(SituationalFlags {
+            open: req.is_open || parsed.called_melds.iter().any(|m| m.meld.is_open()),
+            riichi: req.is_riichi || req.is_double_riichi,
+            double_riichi: req.is_double_riichi,
+            ippatsu: req.is_ippatsu,
+            rinshan: req.is_rinshan,
+            chankan: req.is_chankan,
+            last_tile: req.is_last_tile,
+            tenhou: req.is_tenhou,
+            chiihou: req.is_chiihou,
+        });
+    if let Some(tile) = winning_tile {
+        context = context.with_winning_tile(tile);
+    }
+    context.chankan_tile = chankan_tile;
+    if let Err(e) = context.validate_chankan() {
+        errors.push(ScoreError::ContradictoryFlags(e));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok((parsed, context))
+}
+
+/// Parse each tile string, recording failures and keeping the tiles that parse
+fn parse_tiles(tiles: &[String], errors: &mut Vec<ScoreError>) -> Vec<Tile> {
+    tiles
+        .iter()
+        .filter_map(|s| {
+            s.parse()
+                .map_err(|e: ParseError| errors.push(e.into()))
+                .ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::Suit;
+
+    fn make_request(hand: &str) -> ScoreRequest {
+        ScoreRequest {
+            hand: hand.to_string(),
+            winning_tile: None,
+            is_tsumo: false,
+            is_riichi: false,
+            is_double_riichi: false,
+            is_ippatsu: false,
+            round_wind: "east".to_string(),
+            seat_wind: "east".to_string(),
+            dora_indicators: vec![],
+            ura_dora_indicators: vec![],
+            is_last_tile: false,
+            is_rinshan: false,
+            is_chankan: false,
+            chankan_tile: None,
+            is_tenhou: false,
+            is_chiihou: false,
+            is_open: false,
+            infer_winning_tile: true,
+            discards: vec![],
+            honba: 0,
+            riichi_sticks: 0,
+        }
+    }
+
+    #[test]
+    fn test_build_context_matches_manual_context() {
+        let mut request = make_request("234m340p456s678m66p");
+        request.winning_tile = Some("6p".to_string());
+        request.is_tsumo = true;
+        request.is_riichi = true;
+        request.is_ippatsu = true;
+        request.round_wind = "south".to_string();
+        request.seat_wind = "w".to_string();
+        request.dora_indicators = vec!["1m".to_string()];
+        request.ura_dora_indicators = vec!["9p".to_string()];
+        request.honba = 2;
+        request.riichi_sticks = 1;
+
+        let expected = GameContext::new(WinType::Tsumo, Honor::South, Honor::West)
+            .with_dora(vec![Tile::suited(Suit::Man, 1)])
+            .with_ura_dora(vec![Tile::suited(Suit::Pin, 9)])
+            .with_aka(1)
+            .riichi()
+            .ippatsu()
+            .with_honba(2)
+            .with_riichi_sticks(1)
+            .with_winning_tile(Tile::suited(Suit::Pin, 6));
+
+        assert_eq!(build_context(&request).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_build_context_open_melds_and_marker() {
+        // A called pon opens the hand; the `=` marker sets a tsumo win on 9s
+        let request = make_request("(111z)234m567p55s78=9s");
+        let parsed = parse_request_hand(&request).unwrap();
+        let context = build_context(&request).unwrap();
+
+        assert_eq!(parsed.tiles.len(), 11);
+        assert!(context.is_open);
+        assert_eq!(context.win_type, WinType::Tsumo);
+        assert_eq!(context.winning_tile, Some(Tile::suited(Suit::Sou, 9)));
+    }
+
+    #[test]
+    fn test_build_context_rejects_marker_conflicts() {
+        // `+` marks a ron win, which the tsumo flag contradicts
+        let mut request = make_request("234m345p456s678m6+6p");
+        request.is_tsumo = true;
+        assert_eq!(
+            build_context(&request).unwrap_err().code(),
+            "contradictory_flags"
+        );
+
+        // The explicit winning tile must be the marked one
+        let mut request = make_request("234m345p456s678m6+6p");
+        request.winning_tile = Some("2m".to_string());
+        assert_eq!(
+            validate_request(&request)
+                .iter()
+                .map(ScoreError::code)
+                .collect::<Vec<_>>(),
+            vec!["contradictory_flags"]
+        );
+
+        request.winning_tile = Some("6p".to_string());
+        let context = build_context(&request).unwrap();
+        assert_eq!(context.win_type, WinType::Ron);
+    }
+
+    #[test]
+    fn test_build_context_errors() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.round_wind = "5z".to_string();
+        assert_eq!(build_context(&request).unwrap_err().code(), "invalid_wind");
+
+        let mut request = make_request("234m345p456s678m66p");
+        request.winning_tile = Some("1m".to_string());
+        assert_eq!(
+            build_context(&request).unwrap_err(),
+            ScoreError::WinningTileNotInHand(Tile::suited(Suit::Man, 1))
+        );
+
+        let mut request = make_request("234m345p456s678m66p");
+        request.is_tenhou = true;
+        request.is_chiihou = true;
+        assert_eq!(
+            build_context(&request).unwrap_err().code(),
+            "contradictory_flags"
+        );
+    }
+
+    #[test]
+    fn test_build_context_checks_chankan_tile() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.winning_tile = Some("6p".to_string());
+        request.is_chankan = true;
+        request.chankan_tile = Some("6p".to_string());
+        let context = build_context(&request).unwrap();
+        assert_eq!(context.chankan_tile, Some(Tile::suited(Suit::Pin, 6)));
+
+        request.chankan_tile = Some("3m".to_string());
+        assert_eq!(
+            build_context(&request).unwrap_err().code(),
+            "contradictory_flags"
+        );
+
+        request.chankan_tile = Some("6p".to_string());
+        request.is_chankan = false;
+        assert_eq!(validate_request(&request).len(), 1);
+    }
+
+    #[test]
+    fn test_validate_request_reports_every_problem() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.round_wind = "5z".to_string();
+        request.seat_wind = "middle".to_string();
+        request.dora_indicators = vec!["1m".to_string(), "8z".to_string()];
+        request.winning_tile = Some("1m".to_string());
+        request.is_tenhou = true;
+        request.is_chiihou = true;
+
+        let errors = validate_request(&request);
+        let codes: Vec<&str> = errors.iter().map(|e| e.code()).collect();
+
+        assert_eq!(
+            codes,
+            vec![
+                "invalid_wind",
+                "invalid_wind",
+                "contradictory_flags",
+                "parse_error",
+                "winning_tile_not_in_hand",
+            ]
+        );
+        assert_eq!(build_context(&request).unwrap_err(), errors[0]);
+        assert!(validate_request(&make_request("234m345p456s678m66p")).is_empty());
+    }
+}
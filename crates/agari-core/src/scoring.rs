@@ -6,13 +6,20 @@
 //! 3. Whether the winner is dealer or not
 //! 4. Whether the win was by tsumo or ron
 
+use std::cmp::Ordering;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::context::{GameContext, WinType};
-use crate::hand::{HandStructure, Meld};
-use crate::tile::{Honor, Tile};
-use crate::wait::{best_wait_type_for_scoring, is_pinfu};
-use crate::yaku::YakuResult;
+use crate::context::{DoraCount, GameContext, ScoreLimits, WinType, YakuRules};
+use crate::hand::{
+    HandStructure, KanType, Meld, collect_meld_tiles, decompose_hand, decompose_hand_with_melds,
+};
+use crate::parse::TileCounts;
+use crate::shanten::calculate_shanten_with_melds;
+use crate::tile::{Suit, Tile, all_tiles};
+use crate::wait::{best_wait_type_for_scoring, calculate_waits, is_pinfu};
+use crate::yaku::{Yaku, YakuResult, detect_yaku_with_context, displayed_yaku};
 
 /// Score limit levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -36,14 +43,19 @@ pub enum ScoreLevel {
 impl ScoreLevel {
     /// Basic points for this score level (before dealer/tsumo multipliers)
     pub fn basic_points(&self) -> u32 {
+        self.basic_points_with_limits(&ScoreLimits::default())
+    }
+
+    /// Basic points for this score level under a custom limit table
+    pub fn basic_points_with_limits(&self, limits: &ScoreLimits) -> u32 {
         match self {
             ScoreLevel::Normal => 0, // Calculated from fu
-            ScoreLevel::Mangan => 2000,
-            ScoreLevel::Haneman => 3000,
-            ScoreLevel::Baiman => 4000,
-            ScoreLevel::Sanbaiman => 6000,
-            ScoreLevel::Yakuman => 8000,
-            ScoreLevel::DoubleYakuman => 16000,
+            ScoreLevel::Mangan => limits.mangan,
+            ScoreLevel::Haneman => limits.haneman,
+            ScoreLevel::Baiman => limits.baiman,
+            ScoreLevel::Sanbaiman => limits.sanbaiman,
+            ScoreLevel::Yakuman => limits.yakuman,
+            ScoreLevel::DoubleYakuman => limits.yakuman * 2,
         }
     }
 
@@ -80,6 +92,9 @@ pub struct FuBreakdown {
     pub pair: u8,       // Fu from yakuhai pair
     pub wait: u8,       // Fu from wait type
     pub raw_total: u8,  // Sum before rounding
+    /// Open hand whose rounded total was raised to the 30 fu minimum
+    #[serde(default)]
+    pub floored_to_open_minimum: bool,
 }
 
 /// Payment structure for a winning hand
@@ -107,6 +122,101 @@ pub struct ScoringResult {
     /// True when yakuman-level score is reached through accumulated han (13+)
     /// rather than through actual yakuman yaku patterns
     pub is_counted_yakuman: bool,
+    /// Points collected including riichi sticks: `payment.total` plus 1000 per
+    /// stick on the table
+    #[serde(default)]
+    pub total_with_sticks: u32,
+}
+
+/// Terse one-line rendering for logs, e.g. `5han/40fu Mangan 8000 (ron)`
+///
+/// Tsumo wins show the split: `(tsumo 2000/4000)` for a non-dealer, or
+/// `(tsumo 4000 all)` for the dealer.
+impl fmt::Display for ScoringResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}han/{}fu", self.han, self.fu.total)?;
+
+        let level = if self.is_counted_yakuman {
+            "Counted Yakuman"
+        } else {
+            self.score_level.name()
+        };
+        if !level.is_empty() {
+            write!(f, " {}", level)?;
+        }
+        write!(f, " {}", self.payment.total)?;
+
+        match (self.payment.from_dealer, self.payment.from_non_dealer) {
+            (Some(dealer), Some(non_dealer)) => write!(f, " (tsumo {}/{})", non_dealer, dealer),
+            (None, Some(each)) => write!(f, " (tsumo {} all)", each),
+            _ => write!(f, " (ron)"),
+        }
+    }
+}
+
+/// Frontend-neutral summary of one scored interpretation
+///
+/// Collects the fields every output format needs from a structure, its yaku and
+/// score, so the CLI and wasm bindings only have to choose names and layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreSummary {
+    pub structure: HandStructure,
+    /// Yaku with the han to display for each (see `yaku::displayed_yaku`)
+    pub yaku: Vec<(Yaku, u8)>,
+    /// Han from yaku alone
+    pub yaku_han: u8,
+    /// Han including dora
+    pub total_han: u8,
+    pub dora: DoraCount,
+    pub fu: FuResult,
+    pub score_level: ScoreLevel,
+    pub is_counted_yakuman: bool,
+    pub payment: Payment,
+    /// Payment total plus riichi sticks collected
+    pub total_with_sticks: u32,
+    pub is_dealer: bool,
+    pub winning_tile: Option<Tile>,
+}
+
+impl ScoreSummary {
+    /// Display name for the score level, distinguishing counted yakuman
+    pub fn score_level_name(&self) -> &'static str {
+        if self.is_counted_yakuman {
+            "Counted Yakuman"
+        } else {
+            self.score_level.name()
+        }
+    }
+}
+
+impl From<(&HandStructure, &YakuResult, &ScoringResult, &GameContext)> for ScoreSummary {
+    fn from(
+        (structure, yaku_result, score, context): (
+            &HandStructure,
+            &YakuResult,
+            &ScoringResult,
+            &GameContext,
+        ),
+    ) -> Self {
+        ScoreSummary {
+            structure: structure.clone(),
+            yaku: displayed_yaku(yaku_result, context),
+            yaku_han: yaku_result.total_han,
+            total_han: score.han,
+            dora: DoraCount {
+                regular: yaku_result.regular_dora,
+                ura: yaku_result.ura_dora,
+                aka: yaku_result.aka_dora,
+            },
+            fu: score.fu.clone(),
+            score_level: score.score_level,
+            is_counted_yakuman: score.is_counted_yakuman,
+            payment: score.payment.clone(),
+            total_with_sticks: score.total_with_sticks,
+            is_dealer: score.is_dealer,
+            winning_tile: context.winning_tile,
+        }
+    }
 }
 
 // ============================================================================
@@ -149,6 +259,20 @@ pub fn calculate_fu(structure: &HandStructure, context: &GameContext) -> FuResul
     }
 }
 
+/// Calculate fu as if the hand had been won by `win_type`
+///
+/// Lighter than [`score_both_win_types`]: yaku are not re-detected, so this shows
+/// only the fu difference between tsumo and ron.
+pub fn fu_for_win_type(
+    structure: &HandStructure,
+    context: &GameContext,
+    win_type: WinType,
+) -> FuResult {
+    let mut context = context.clone();
+    context.win_type = win_type;
+    calculate_fu(structure, &context)
+}
+
 /// Calculate fu for a standard hand (4 melds + pair)
 fn calculate_standard_fu(melds: &[Meld], pair: Tile, context: &GameContext) -> FuResult {
     let mut breakdown = FuBreakdown {
@@ -233,16 +357,23 @@ fn calculate_standard_fu(melds: &[Meld], pair: Tile, context: &GameContext) -> F
         + breakdown.wait;
 
     // Round up to nearest 10
-    let total = round_up_to_10(breakdown.raw_total);
+    let total = round_fu(breakdown.raw_total);
 
     // Special case: open hand with no fu beyond base = 30 fu minimum
     // (An open hand with all sequences and no yakuhai pair is still 30 fu)
     let total = if context.is_open && total < 30 {
+        breakdown.floored_to_open_minimum = true;
         30
     } else {
         total
     };
 
+    // 20 fu only exists as the pinfu tsumo special case returned above
+    debug_assert!(
+        total != 20 || (is_pinfu_hand && context.win_type == WinType::Tsumo),
+        "20 fu is only possible with pinfu tsumo"
+    );
+
     FuResult { total, breakdown }
 }
 
@@ -384,31 +515,25 @@ fn meld_fu(meld: &Meld) -> u8 {
 /// Calculate fu for the pair
 fn pair_fu(pair: Tile, context: &GameContext) -> u8 {
     match pair {
-        Tile::Honor(honor) => {
-            match honor {
-                // Dragons always give 2 fu
-                Honor::White | Honor::Green | Honor::Red => 2,
-
-                // Winds give 2 fu if they're value winds
-                // Double wind (both round and seat) gives 2 fu (some rules say 4)
-                wind => {
-                    let mut fu = 0;
-                    if wind == context.round_wind {
-                        fu += 2;
-                    }
-                    if wind == context.seat_wind {
-                        fu += 2;
-                    }
-                    fu
-                }
+        // Dragons and value winds give 2 fu
+        // Double wind (both round and seat) gives 4 fu (some rules say 2)
+        Tile::Honor(honor) if context.is_value_honor(honor) => {
+            if honor == context.round_wind && honor == context.seat_wind {
+                4
+            } else {
+                2
             }
         }
+        Tile::Honor(_) => 0,
         Tile::Suited { .. } => 0, // Suited pairs give no fu
     }
 }
 
-/// Round up to the nearest 10
-fn round_up_to_10(value: u8) -> u8 {
+/// Round fu up to the nearest 10
+///
+/// Applies to every hand except chiitoitsu, which is fixed at 25 fu and must not
+/// be rounded; callers handle that case themselves.
+pub fn round_fu(value: u8) -> u8 {
     value.div_ceil(10) * 10
 }
 
@@ -418,13 +543,28 @@ fn round_up_to_10(value: u8) -> u8 {
 
 /// Determine the score level based on han and fu
 pub fn determine_score_level(han: u8, fu: u8, is_yakuman: bool) -> ScoreLevel {
+    determine_score_level_with_rules(han, fu, is_yakuman, &YakuRules::default())
+}
+
+/// Determine the score level based on han and fu under the given rule variations
+///
+/// Without `kazoe_yakuman`, counted yakuman is capped at sanbaiman; with `kiriage`,
+/// 4 han 30 fu and 3 han 60 fu are rounded up to mangan.
+pub fn determine_score_level_with_rules(
+    han: u8,
+    fu: u8,
+    is_yakuman: bool,
+    rules: &YakuRules,
+) -> ScoreLevel {
+    let kiriage = rules.kiriage && ((han == 4 && fu >= 30) || (han == 3 && fu >= 60));
+
     if is_yakuman {
         if han >= 26 {
             ScoreLevel::DoubleYakuman
         } else {
             ScoreLevel::Yakuman
         }
-    } else if han >= 13 {
+    } else if han >= 13 && rules.kazoe_yakuman {
         ScoreLevel::Yakuman // Counted yakuman (kazoe yakuman)
     } else if han >= 11 {
         ScoreLevel::Sanbaiman
@@ -432,38 +572,103 @@ pub fn determine_score_level(han: u8, fu: u8, is_yakuman: bool) -> ScoreLevel {
         ScoreLevel::Baiman
     } else if han >= 6 {
         ScoreLevel::Haneman
-    } else if han >= 5 || (han == 4 && fu >= 40) || (han == 3 && fu >= 70) {
+    } else if han >= 5 || (han == 4 && fu >= 40) || (han == 3 && fu >= 70) || kiriage {
         ScoreLevel::Mangan
     } else {
         ScoreLevel::Normal
     }
 }
 
+/// Minimum number of dora needed to push a hand into the next score level
+///
+/// `yaku_han` is the han from yaku alone. Returns 0 if the hand is already at
+/// counted yakuman, the highest level dora can reach.
+pub fn dora_to_next_level(yaku_han: u8, fu: u8) -> u8 {
+    let current = determine_score_level(yaku_han, fu, false);
+    (1..=13u8)
+        .find(|&dora| determine_score_level(yaku_han.saturating_add(dora), fu, false) > current)
+        .unwrap_or(0)
+}
+
+/// Additional fu needed to make a hand mangan (4 han needs 40 fu, 3 han needs 70 fu)
+///
+/// Returns `None` if the hand is already mangan (including any hand with 5+ han),
+/// or if no achievable fu (up to 110) would reach mangan at this han.
+pub fn fu_to_mangan(han: u8, fu: u8) -> Option<u8> {
+    if determine_score_level(han, fu, false) >= ScoreLevel::Mangan {
+        return None;
+    }
+    (round_fu(fu)..=110)
+        .step_by(10)
+        .find(|&target| determine_score_level(han, target, false) >= ScoreLevel::Mangan)
+        .map(|target| target - fu)
+}
+
+/// Simplified points for teaching, ignoring fu
+///
+/// Non-dealer wins are a flat 1000 per han up to 4000 at 4 han, staying below
+/// mangan; from 5 han the named limits apply as usual (mangan 8000, haneman 12000,
+/// ..., counted yakuman 32000). The dealer gets 1.5× (1500 at 1 han). 0 han scores 0.
+pub fn teaching_points(han: u8, is_dealer: bool) -> u32 {
+    let non_dealer = match han {
+        0 => 0,
+        1..=4 => 1000 * han as u32,
+        _ => determine_score_level(han, 30, false).basic_points() * 4,
+    };
+
+    if is_dealer {
+        non_dealer * 3 / 2
+    } else {
+        non_dealer
+    }
+}
+
 /// Calculate basic points from han and fu
 ///
 /// Basic formula: fu × 2^(han+2)
 /// Capped at 2000 (mangan)
 pub fn calculate_basic_points(han: u8, fu: u8, is_yakuman: bool) -> u32 {
-    let level = determine_score_level(han, fu, is_yakuman);
+    basic_points_for_level(
+        determine_score_level(han, fu, is_yakuman),
+        han,
+        fu,
+        &ScoreLimits::default(),
+    )
+}
 
+/// Basic points for an already-determined score level
+fn basic_points_for_level(level: ScoreLevel, han: u8, fu: u8, limits: &ScoreLimits) -> u32 {
     if level != ScoreLevel::Normal {
-        return level.basic_points();
+        return level.basic_points_with_limits(limits);
     }
 
     // Normal calculation: fu × 2^(han+2)
     let basic = (fu as u32) * 2u32.pow((han + 2) as u32);
 
     // Cap at mangan (2000)
-    basic.min(2000)
+    basic.min(limits.mangan)
 }
 
 /// Calculate final payment based on basic points, dealer status, and win type
 pub fn calculate_payment(basic_points: u32, is_dealer: bool, win_type: WinType) -> Payment {
+    calculate_payment_with_honba(basic_points, is_dealer, win_type, 0)
+}
+
+/// Calculate final payment including honba
+///
+/// Each honba adds 300 to a ron payment, or 100 from each payer on tsumo.
+pub fn calculate_payment_with_honba(
+    basic_points: u32,
+    is_dealer: bool,
+    win_type: WinType,
+    honba: u8,
+) -> Payment {
+    let honba = honba as u32;
     match win_type {
         WinType::Tsumo => {
             if is_dealer {
                 // Dealer tsumo: each non-dealer pays basic × 2
-                let from_each = round_up_to_100(basic_points * 2);
+                let from_each = round_points(basic_points * 2) + 100 * honba;
                 Payment {
                     total: from_each * 3,
                     from_non_dealer: Some(from_each),
@@ -472,8 +677,8 @@ pub fn calculate_payment(basic_points: u32, is_dealer: bool, win_type: WinType)
                 }
             } else {
                 // Non-dealer tsumo: dealer pays basic × 2, others pay basic × 1
-                let from_dealer = round_up_to_100(basic_points * 2);
-                let from_non_dealer = round_up_to_100(basic_points);
+                let from_dealer = round_points(basic_points * 2) + 100 * honba;
+                let from_non_dealer = round_points(basic_points) + 100 * honba;
                 Payment {
                     total: from_dealer + (from_non_dealer * 2),
                     from_non_dealer: Some(from_non_dealer),
@@ -485,7 +690,7 @@ pub fn calculate_payment(basic_points: u32, is_dealer: bool, win_type: WinType)
         WinType::Ron => {
             // Ron: discarder pays everything
             let multiplier = if is_dealer { 6 } else { 4 };
-            let from_discarder = round_up_to_100(basic_points * multiplier);
+            let from_discarder = round_points(basic_points * multiplier) + 300 * honba;
             Payment {
                 total: from_discarder,
                 from_non_dealer: None,
@@ -496,11 +701,155 @@ pub fn calculate_payment(basic_points: u32, is_dealer: bool, win_type: WinType)
     }
 }
 
-/// Round up to the nearest 100
-fn round_up_to_100(value: u32) -> u32 {
+/// Round points up to the nearest 100, as done for every individual payment
+pub fn round_points(value: u32) -> u32 {
     value.div_ceil(100) * 100
 }
 
+/// Reasons [`seat_deltas`] cannot settle a win
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatError {
+    /// Winner seat is not 0-3
+    WinnerOutOfRange(usize),
+    /// Dealer seat is not 0-3
+    DealerOutOfRange(usize),
+    /// Whether the winner sits in the dealer seat disagrees with `score.is_dealer`
+    DealerMismatch { winner: usize, is_dealer: bool },
+    /// `score` was computed with a different honba count than the one given
+    HonbaMismatch { in_score: u32, given: u8 },
+    /// `score` was computed with a different riichi stick count than the one given
+    RiichiSticksMismatch { in_score: u32, given: u8 },
+    /// A ron score was given no discarder
+    MissingDiscarder,
+    /// A tsumo score was given a discarder
+    UnexpectedDiscarder,
+    /// Discarder seat is not 0-3, or is the winner
+    InvalidDiscarder(usize),
+}
+
+impl fmt::Display for SeatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeatError::WinnerOutOfRange(seat) => {
+                write!(f, "Winner seat {} is out of range", seat)
+            }
+            SeatError::DealerOutOfRange(seat) => {
+                write!(f, "Dealer seat {} is out of range", seat)
+            }
+            SeatError::DealerMismatch { winner, is_dealer } => write!(
+                f,
+                "Winner seat {} does not match a {} score",
+                winner,
+                if *is_dealer { "dealer" } else { "non-dealer" }
+            ),
+            SeatError::HonbaMismatch { in_score, given } => write!(
+                f,
+                "Score includes {} honba but {} were given",
+                in_score, given
+            ),
+            SeatError::RiichiSticksMismatch { in_score, given } => write!(
+                f,
+                "Score includes {} riichi sticks but {} were given",
+                in_score, given
+            ),
+            SeatError::MissingDiscarder => write!(f, "Ron needs a discarder"),
+            SeatError::UnexpectedDiscarder => write!(f, "Tsumo has no discarder"),
+            SeatError::InvalidDiscarder(seat) => write!(f, "Invalid discarder seat {}", seat),
+        }
+    }
+}
+
+impl std::error::Error for SeatError {}
+
+/// Net point change for each of four seats after a win
+///
+/// Seats are table positions 0-3 and `dealer` is whichever seat is East this hand.
+/// The payment is rebuilt from `score.basic_points` with `honba`, and
+/// `riichi_sticks` (1000 each) go to the winner from the table pot. A score
+/// computed with honba or sticks in its own context must carry the same counts as
+/// the arguments, so nothing is dropped or counted twice; a score computed
+/// without them is always accepted.
+///
+/// `discarder` is required for a ron and must be `None` for a tsumo. Inconsistent
+/// seats or counts are reported as a [`SeatError`] instead of panicking.
+pub fn seat_deltas(
+    score: &ScoringResult,
+    winner: usize,
+    discarder: Option<usize>,
+    dealer: usize,
+    honba: u8,
+    riichi_sticks: u8,
+) -> Result<[i32; 4], SeatError> {
+    if winner >= 4 {
+        return Err(SeatError::WinnerOutOfRange(winner));
+    }
+    if dealer >= 4 {
+        return Err(SeatError::DealerOutOfRange(dealer));
+    }
+    if (winner == dealer) != score.is_dealer {
+        return Err(SeatError::DealerMismatch {
+            winner,
+            is_dealer: score.is_dealer,
+        });
+    }
+
+    let win_type = if score.payment.from_discarder.is_some() {
+        WinType::Ron
+    } else {
+        WinType::Tsumo
+    };
+
+    // Honba already in the score's payment: 300 per honba in total either way
+    let base = calculate_payment(score.basic_points, score.is_dealer, win_type);
+    let honba_in_score = score.payment.total.saturating_sub(base.total) / 300;
+    if honba_in_score != 0 && honba_in_score != honba as u32 {
+        return Err(SeatError::HonbaMismatch {
+            in_score: honba_in_score,
+            given: honba,
+        });
+    }
+    let sticks_in_score = score.total_with_sticks.saturating_sub(score.payment.total) / 1000;
+    if sticks_in_score != 0 && sticks_in_score != riichi_sticks as u32 {
+        return Err(SeatError::RiichiSticksMismatch {
+            in_score: sticks_in_score,
+            given: riichi_sticks,
+        });
+    }
+
+    let payment =
+        calculate_payment_with_honba(score.basic_points, score.is_dealer, win_type, honba);
+
+    let mut deltas = [0i32; 4];
+
+    match (payment.from_discarder, discarder) {
+        (Some(_), None) => return Err(SeatError::MissingDiscarder),
+        (None, Some(_)) => return Err(SeatError::UnexpectedDiscarder),
+        (Some(_), Some(discarder)) if discarder >= 4 || discarder == winner => {
+            return Err(SeatError::InvalidDiscarder(discarder));
+        }
+        (Some(from_discarder), Some(discarder)) => {
+            let owed = from_discarder as i32;
+            deltas[discarder] -= owed;
+            deltas[winner] += owed;
+        }
+        (None, None) => {
+            for seat in (0..4).filter(|&seat| seat != winner) {
+                let share = if seat == dealer {
+                    payment.from_dealer
+                } else {
+                    payment.from_non_dealer
+                };
+                let owed = share.unwrap_or(0) as i32;
+                deltas[seat] -= owed;
+                deltas[winner] += owed;
+            }
+        }
+    }
+
+    deltas[winner] += riichi_sticks as i32 * 1000;
+    Ok(deltas)
+}
+
 // ============================================================================
 // Complete Scoring
 // ============================================================================
@@ -526,20 +875,24 @@ pub fn calculate_score(
     let han = yaku_result.total_han_with_dora();
 
     // Determine score level
-    let score_level = determine_score_level(han, fu.total, yaku_result.is_yakuman);
+    let score_level =
+        determine_score_level_with_rules(han, fu.total, yaku_result.is_yakuman, &context.rules);
 
     // Calculate basic points
-    let basic_points = calculate_basic_points(han, fu.total, yaku_result.is_yakuman);
+    let basic_points = basic_points_for_level(score_level, han, fu.total, &context.limits);
 
     // Calculate payment
     let is_dealer = context.is_dealer();
-    let payment = calculate_payment(basic_points, is_dealer, context.win_type);
+    let payment =
+        calculate_payment_with_honba(basic_points, is_dealer, context.win_type, context.honba);
 
     // Counted yakuman: reached yakuman level (13+ han) without actual yakuman yaku
     let is_counted_yakuman = (score_level == ScoreLevel::Yakuman
         || score_level == ScoreLevel::DoubleYakuman)
         && !yaku_result.is_yakuman;
 
+    let total_with_sticks = payment.total + 1000 * context.riichi_sticks as u32;
+
     ScoringResult {
         fu,
         han,
@@ -548,6 +901,509 @@ pub fn calculate_score(
         payment,
         is_dealer,
         is_counted_yakuman,
+        total_with_sticks,
+    }
+}
+
+/// Score Nagashi Mangan: every discard a terminal or honor at exhaustive draw
+///
+/// Paid like a mangan tsumo (8000 non-dealer, 12000 dealer). `discards` is the
+/// player's whole discard pond; the caller must check that none of them were
+/// called, since a called discard voids nagashi. Returns `None` for an empty pond
+/// or one containing a simple. The result has no han or fu.
+pub fn calculate_nagashi_mangan(discards: &[Tile], is_dealer: bool) -> Option<ScoringResult> {
+    if discards.is_empty() || !discards.iter().all(|t| t.is_terminal_or_honor()) {
+        return None;
+    }
+
+    let score_level = ScoreLevel::Mangan;
+    let basic_points = score_level.basic_points();
+    let payment = calculate_payment(basic_points, is_dealer, WinType::Tsumo);
+    let total_with_sticks = payment.total;
+    Some(ScoringResult {
+        fu: FuResult {
+            total: 0,
+            breakdown: FuBreakdown::default(),
+        },
+        han: 0,
+        score_level,
+        basic_points,
+        payment,
+        is_dealer,
+        is_counted_yakuman: false,
+        total_with_sticks,
+    })
+}
+
+/// Compare two scores: higher payment, then higher han, then lower fu
+pub fn compare_scores(a: &ScoringResult, b: &ScoringResult) -> Ordering {
+    a.payment
+        .total
+        .cmp(&b.payment.total)
+        .then_with(|| a.han.cmp(&b.han))
+        .then_with(|| b.fu.total.cmp(&a.fu.total))
+}
+
+/// One decomposition of a hand with its yaku and score under a context
+#[derive(Debug, Clone)]
+pub struct Interpretation {
+    pub structure: HandStructure,
+    pub yaku: YakuResult,
+    pub score: ScoringResult,
+}
+
+impl Interpretation {
+    /// Detect yaku for `structure` and score it
+    ///
+    /// `all_counts` holds every tile in the hand, called melds included, for dora.
+    /// With `rules.prefer_pinfu`, a wait that reads as both ryanmen and a fu-paying
+    /// shape is scored both ways and the reading that pays more is kept.
+    pub fn new(structure: &HandStructure, all_counts: &TileCounts, context: &GameContext) -> Self {
+        Self::new_adjusted(structure, all_counts, context, |_| {})
+    }
+
+    /// [`Interpretation::new`], with `adjust` applied to each reading's yaku before
+    /// it is scored
+    fn new_adjusted(
+        structure: &HandStructure,
+        all_counts: &TileCounts,
+        context: &GameContext,
+        adjust: impl Fn(&mut YakuResult),
+    ) -> Self {
+        let reading = |context: &GameContext| {
+            let mut yaku = detect_yaku_with_context(structure, all_counts, context);
+            adjust(&mut yaku);
+            Self::from_yaku(structure, yaku, context)
+        };
+
+        let interpretation = reading(context);
+        if !context.rules.prefer_pinfu || !has_ambiguous_pinfu_wait(structure, context) {
+            return interpretation;
+        }
+
+        let max_fu = reading(&context.clone().no_prefer_pinfu());
+        if max_fu.score.payment.total > interpretation.score.payment.total {
+            max_fu
+        } else {
+            interpretation
+        }
+    }
+
+    /// Score `structure` with yaku that were already detected (and possibly adjusted)
+    pub fn from_yaku(structure: &HandStructure, yaku: YakuResult, context: &GameContext) -> Self {
+        let score = calculate_score(structure, &yaku, context);
+        Interpretation {
+            structure: structure.clone(),
+            yaku,
+            score,
+        }
+    }
+
+    pub fn has_yaku(&self) -> bool {
+        !self.yaku.yaku_list.is_empty()
+    }
+}
+
+/// Whether the winning tile reads as pinfu's ryanmen and also as a wait with fu
+fn has_ambiguous_pinfu_wait(structure: &HandStructure, context: &GameContext) -> bool {
+    context.winning_tile.is_some_and(|tile| {
+        is_pinfu(structure, tile, context)
+            && best_wait_type_for_scoring(structure, tile).is_some_and(|wait| wait.fu() > 0)
+    })
+}
+
+/// Compare two interpretations: any yaku beats none, then [`compare_scores`]
+///
+/// This is the one preference every frontend uses to pick the reading to show.
+pub fn compare_interpretations(a: &Interpretation, b: &Interpretation) -> Ordering {
+    a.has_yaku()
+        .cmp(&b.has_yaku())
+        .then_with(|| compare_scores(&a.score, &b.score))
+}
+
+/// Score every structure under `context`, best first by [`compare_interpretations`]
+pub fn rank_interpretations(
+    structures: &[HandStructure],
+    all_counts: &TileCounts,
+    context: &GameContext,
+) -> Vec<Interpretation> {
+    let mut ranked: Vec<Interpretation> = structures
+        .iter()
+        .map(|structure| Interpretation::new(structure, all_counts, context))
+        .collect();
+    ranked.sort_by(|a, b| compare_interpretations(b, a));
+    ranked
+}
+
+/// Score every decomposition of a complete closed hand and return the best result.
+///
+/// Interpretations with yaku are preferred over those without; when `require_yaku`
+/// is set, yakuless interpretations are dropped entirely. Returns `None` if the hand
+/// has no (qualifying) structure.
+pub fn best_score_for_counts(
+    counts: &TileCounts,
+    context: &GameContext,
+    require_yaku: bool,
+) -> Option<ScoringResult> {
+    best_score_with_melds(counts, &[], context, require_yaku)
+}
+
+/// [`best_score_for_counts`] for a hand with called melds
+///
+/// `counts` holds the concealed tiles only; the melds' tiles are added back for dora.
+pub fn best_score_with_melds(
+    counts: &TileCounts,
+    called_melds: &[Meld],
+    context: &GameContext,
+    require_yaku: bool,
+) -> Option<ScoringResult> {
+    let structures = if called_melds.is_empty() {
+        decompose_hand(counts)
+    } else {
+        decompose_hand_with_melds(counts, called_melds)
+    };
+    rank_interpretations(
+        &structures,
+        &counts_with_melds(counts, called_melds),
+        context,
+    )
+    .into_iter()
+    .next()
+    .filter(|best| !require_yaku || best.has_yaku())
+    .map(|best| best.score)
+}
+
+/// Concealed counts plus the tiles of each called meld
+fn counts_with_melds(counts: &TileCounts, called_melds: &[Meld]) -> TileCounts {
+    let mut all = counts.clone();
+    for tile in collect_meld_tiles(called_melds) {
+        *all.entry(tile).or_insert(0) += 1;
+    }
+    all
+}
+
+/// Score a complete hand as both a tsumo and a ron win on the same tile
+///
+/// Returns `(tsumo_result, ron_result)`. The context is cloned for each win type,
+/// so differences such as menzen tsumo and tsumo/menzen-ron fu appear naturally.
+/// Returns `None` if `counts` is not a complete (decomposable) hand.
+pub fn score_both_win_types(
+    counts: &TileCounts,
+    context: &GameContext,
+    winning_tile: Tile,
+) -> Option<(ScoringResult, ScoringResult)> {
+    let score_for = |win_type: WinType| {
+        let mut ctx = context.clone().with_winning_tile(winning_tile);
+        ctx.win_type = win_type;
+        best_score_for_counts(counts, &ctx, false)
+    };
+
+    Some((score_for(WinType::Tsumo)?, score_for(WinType::Ron)?))
+}
+
+/// Whether a complete hand has a yaku on tsumo but none on ron
+///
+/// True for closed hands whose only yaku is menzen tsumo, which must not call ron.
+pub fn tsumo_only(counts: &TileCounts, context: &GameContext, winning_tile: Tile) -> bool {
+    let has_yaku = |win_type: WinType| {
+        let mut ctx = context.clone().with_winning_tile(winning_tile);
+        ctx.win_type = win_type;
+        decompose_hand(counts).iter().any(|structure| {
+            !detect_yaku_with_context(structure, counts, &ctx)
+                .yaku_list
+                .is_empty()
+        })
+    };
+
+    !has_yaku(WinType::Ron) && has_yaku(WinType::Tsumo)
+}
+
+/// Score a complete hand as if `assumed_ura` ura dora had been revealed
+///
+/// The assumed ura are added to the dora count directly, so no ura indicators are
+/// needed. Useful for estimating the value of riichi before the hand is won.
+/// Returns `None` if `counts` is not a complete (decomposable) hand.
+pub fn score_with_assumed_ura(
+    counts: &TileCounts,
+    context: &GameContext,
+    winning_tile: Tile,
+    assumed_ura: u8,
+) -> Option<ScoringResult> {
+    let ctx = context.clone().with_winning_tile(winning_tile);
+
+    decompose_hand(counts)
+        .iter()
+        .map(|structure| {
+            Interpretation::new_adjusted(structure, counts, &ctx, |yaku| {
+                yaku.ura_dora += assumed_ura;
+                yaku.dora_count += assumed_ura;
+            })
+        })
+        .max_by(compare_interpretations)
+        .map(|best| best.score)
+}
+
+/// Score a complete hand as if every five it holds were red (one per suit)
+///
+/// For hands written without `0` notation: each suit with a 5 in `counts` adds one
+/// aka dora, replacing the context's `aka_count`. Useful as a scoring ceiling.
+/// Returns `None` if `counts` is not a complete (decomposable) hand.
+pub fn score_with_max_aka(
+    counts: &TileCounts,
+    context: &GameContext,
+    winning_tile: Tile,
+) -> Option<ScoringResult> {
+    let aka = [Suit::Man, Suit::Pin, Suit::Sou]
+        .into_iter()
+        .filter(|&suit| counts.get(&Tile::suited(suit, 5)).is_some_and(|&c| c > 0))
+        .count() as u8;
+    let ctx = context
+        .clone()
+        .with_winning_tile(winning_tile)
+        .with_aka(aka);
+
+    best_score_for_counts(counts, &ctx, false)
+}
+
+/// Rough chance of 0, 1 and 2 ura dora when a riichi hand wins, used by [`riichi_ev`]
+const URA_DORA_WEIGHTS: [f64; 3] = [0.7, 0.25, 0.05];
+
+/// Estimate the points a tenpai hand (13 concealed tiles) wins if it declares riichi
+///
+/// The model is deliberately simple: every winning tile is taken as equally likely,
+/// the win type comes from `context`, and ura dora follow `URA_DORA_WEIGHTS`
+/// (no ura 70%, one 25%, two 5%). Each outcome is scored with
+/// [`score_with_assumed_ura`]; riichi is itself a yaku, so every wait counts.
+/// Returns `None` if riichi can't be declared: the context is open, the hand isn't
+/// 13 concealed tiles, or it isn't tenpai.
+pub fn riichi_ev(counts: &TileCounts, context: &GameContext) -> Option<f64> {
+    let tile_count: usize = counts.values().map(|&c| c as usize).sum();
+    if context.is_open || tile_count != 13 {
+        return None;
+    }
+
+    let waits = calculate_waits(counts);
+    if waits.is_empty() {
+        return None;
+    }
+
+    let ctx = context.clone().riichi();
+    let total: f64 = waits
+        .iter()
+        .map(|&tile| {
+            let mut complete = counts.clone();
+            *complete.entry(tile).or_insert(0) += 1;
+
+            URA_DORA_WEIGHTS
+                .iter()
+                .zip(0u8..)
+                .filter_map(|(weight, ura)| {
+                    let score = score_with_assumed_ura(&complete, &ctx, tile, ura)?;
+                    Some(weight * score.payment.total as f64)
+                })
+                .sum::<f64>()
+        })
+        .sum();
+
+    Some(total / waits.len() as f64)
+}
+
+/// Score the best and runner-up interpretations of a complete hand
+///
+/// Each decomposition is scored once and ranked with [`rank_interpretations`]. The
+/// second element is the next-best distinct structure, or `None` if the hand only
+/// decomposes one way. Useful for auditing close tie-breaks.
+/// Returns `None` if `counts` is not a complete (decomposable) hand.
+pub fn top_two_interpretations(
+    counts: &TileCounts,
+    context: &GameContext,
+) -> Option<(ScoringResult, Option<ScoringResult>)> {
+    let mut ranked = rank_interpretations(&decompose_hand(counts), counts, context)
+        .into_iter()
+        .map(|interpretation| interpretation.score);
+    let best = ranked.next()?;
+    Some((best, ranked.next()))
+}
+
+/// Find the highest-scoring outcome reachable from a tenpai hand (13 concealed tiles)
+///
+/// Every winning tile is tried as both tsumo and ron; interpretations without yaku
+/// are not reachable and are ignored. Returns `None` if no win with yaku exists.
+/// Hands with called melds go through [`max_possible_score_with_melds`]; here
+/// `counts` must be 13 tiles or `None` is returned.
+pub fn max_possible_score(
+    counts: &TileCounts,
+    context: &GameContext,
+) -> Option<(Tile, WinType, ScoringResult)> {
+    max_possible_score_with_melds(counts, &[], context)
+}
+
+/// [`max_possible_score`] for a tenpai hand with called melds
+///
+/// `counts` holds the concealed tiles only (13 minus 3 per called meld). Returns
+/// `None` when the tile count doesn't match the melds, as well as when no win with
+/// yaku exists.
+pub fn max_possible_score_with_melds(
+    counts: &TileCounts,
+    called_melds: &[Meld],
+    context: &GameContext,
+) -> Option<(Tile, WinType, ScoringResult)> {
+    let tile_count: usize = counts.values().map(|&c| c as usize).sum();
+    if tile_count + 3 * called_melds.len() != 13 {
+        return None;
+    }
+
+    let mut best: Option<(Tile, WinType, ScoringResult)> = None;
+
+    for tile in calculate_waits(counts) {
+        let mut complete = counts.clone();
+        *complete.entry(tile).or_insert(0) += 1;
+
+        for win_type in [WinType::Tsumo, WinType::Ron] {
+            let mut ctx = context.clone().with_winning_tile(tile);
+            ctx.win_type = win_type;
+
+            if let Some(score) = best_score_with_melds(&complete, called_melds, &ctx, true) {
+                let is_better = match &best {
+                    None => true,
+                    Some((_, _, best_score)) => compare_scores(&score, best_score).is_gt(),
+                };
+                if is_better {
+                    best = Some((tile, win_type, score));
+                }
+            }
+        }
+    }
+
+    best
+}
+
+/// Fu total for each winning tile of a tenpai hand (13 concealed tiles)
+///
+/// Each wait is completed and given the highest fu any interpretation reaches under
+/// `context` (with the winning tile set). Results follow the order of `calculate_waits`.
+pub fn fu_by_winning_tile(counts: &TileCounts, context: &GameContext) -> Vec<(Tile, u8)> {
+    calculate_waits(counts)
+        .into_iter()
+        .map(|tile| {
+            let mut complete = counts.clone();
+            *complete.entry(tile).or_insert(0) += 1;
+            let ctx = context.clone().with_winning_tile(tile);
+
+            let fu = decompose_hand(&complete)
+                .iter()
+                .map(|structure| calculate_fu(structure, &ctx).total)
+                .max()
+                .unwrap_or(0);
+            (tile, fu)
+        })
+        .collect()
+}
+
+/// How declaring a closed kan changes a hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KanImpact {
+    /// Pinfu was available on some wait before the kan but no longer is
+    pub loses_pinfu: bool,
+    /// Shanten after the kan minus shanten before (negative is an improvement)
+    pub shanten_change: i8,
+    /// Best fu over the waits after the kan minus the best fu before
+    pub fu_change: i8,
+}
+
+/// Compare a 13-tile hand before and after a closed kan on the fourth `kan_tile`
+///
+/// `counts` is the hand before drawing the fourth copy, so it must hold a triplet
+/// of `kan_tile`. Pinfu and fu are judged over every wait of each hand (ron under
+/// `context`); a hand that isn't tenpai has no pinfu and 0 fu.
+///
+/// # Panics
+/// Panics if `counts` holds fewer than three `kan_tile`.
+pub fn kan_impact(counts: &TileCounts, kan_tile: Tile, context: &GameContext) -> KanImpact {
+    assert!(
+        counts.get(&kan_tile).copied().unwrap_or(0) >= 3,
+        "kan_impact needs a triplet of {kan_tile}"
+    );
+
+    let mut after = counts.clone();
+    *after.get_mut(&kan_tile).unwrap() -= 3;
+    after.retain(|_, c| *c > 0);
+    let kan = [Meld::Kan(kan_tile, KanType::Closed)];
+
+    let before_shanten = calculate_shanten_with_melds(counts, 0).shanten;
+    let after_shanten = calculate_shanten_with_melds(&after, 1).shanten;
+    let (before_pinfu, before_fu) = wait_profile(counts, &[], context);
+    let (after_pinfu, after_fu) = wait_profile(&after, &kan, context);
+
+    KanImpact {
+        loses_pinfu: before_pinfu && !after_pinfu,
+        shanten_change: after_shanten - before_shanten,
+        fu_change: after_fu as i8 - before_fu as i8,
+    }
+}
+
+/// Whether any wait allows pinfu, and the best fu over all waits
+fn wait_profile(counts: &TileCounts, melds: &[Meld], context: &GameContext) -> (bool, u8) {
+    let mut pinfu = false;
+    let mut best_fu = 0;
+
+    for tile in all_tiles() {
+        let held = counts.get(&tile).copied().unwrap_or(0);
+        if held >= 4
+            || melds
+                .iter()
+                .any(|m| matches!(m, Meld::Kan(t, _) if *t == tile))
+        {
+            continue;
+        }
+        let mut complete = counts.clone();
+        *complete.entry(tile).or_insert(0) += 1;
+        let ctx = context.clone().with_winning_tile(tile);
+
+        let structures = if melds.is_empty() {
+            decompose_hand(&complete)
+        } else {
+            decompose_hand_with_melds(&complete, melds)
+        };
+        for structure in structures {
+            pinfu |= is_pinfu(&structure, tile, &ctx);
+            best_fu = best_fu.max(calculate_fu(&structure, &ctx).total);
+        }
+    }
+
+    (pinfu, best_fu)
+}
+
+/// Compare two tenpai hands by the best score each can reach on its best wait
+///
+/// Uses [`max_possible_score`]; a hand with no reachable win with yaku ranks
+/// below any hand that has one. `Ordering::Greater` means `a` is worth more.
+/// Both hands must be 13 concealed tiles; see [`compare_tenpai_value_with_melds`].
+pub fn compare_tenpai_value(a: &TileCounts, b: &TileCounts, context: &GameContext) -> Ordering {
+    compare_tenpai_value_with_melds(a, &[], b, &[], context)
+}
+
+/// [`compare_tenpai_value`] for hands that may have called melds
+///
+/// A hand with an open meld is scored as open even if `context` is closed.
+pub fn compare_tenpai_value_with_melds(
+    a: &TileCounts,
+    a_melds: &[Meld],
+    b: &TileCounts,
+    b_melds: &[Meld],
+    context: &GameContext,
+) -> Ordering {
+    let best = |counts: &TileCounts, melds: &[Meld]| {
+        let mut ctx = context.clone();
+        ctx.is_open |= melds.iter().any(Meld::is_open);
+        max_possible_score_with_melds(counts, melds, &ctx)
+    };
+    let best_a = best(a, a_melds);
+    let best_b = best(b, b_melds);
+
+    match (&best_a, &best_b) {
+        (Some((_, _, score_a)), Some((_, _, score_b))) => compare_scores(score_a, score_b),
+        _ => best_a.is_some().cmp(&best_b.is_some()),
     }
 }
 
@@ -611,7 +1467,7 @@ mod tests {
     use super::*;
     use crate::hand::decompose_hand;
     use crate::parse::{parse_hand, to_counts};
-    use crate::tile::Suit;
+    use crate::tile::{Honor, Suit};
     use crate::yaku::detect_yaku_with_context;
 
     // ===== Helper Functions =====
@@ -683,6 +1539,56 @@ mod tests {
         assert!(fu_results.iter().any(|f| f.total == 20));
     }
 
+    #[test]
+    fn test_fu_pinfu_ron_is_30() {
+        // Pinfu on ron keeps the menzen ron 10 fu: 20 + 10 = 30
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 4));
+
+        let tiles = parse_hand("123456m789p234s55p").unwrap();
+        let counts = to_counts(&tiles);
+        for structure in decompose_hand(&counts) {
+            assert_eq!(calculate_fu(&structure, &context).total, 30);
+        }
+    }
+
+    #[test]
+    fn test_fu_open_all_sequence_ron_is_30() {
+        // Open hand, no fu beyond base: 20 raw, floored to 30
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .open()
+            .with_winning_tile(Tile::suited(Suit::Sou, 4));
+
+        let tiles = parse_hand("123456m789p234s55p").unwrap();
+        let counts = to_counts(&tiles);
+        for structure in decompose_hand(&counts) {
+            let fu = calculate_fu(&structure, &context);
+            assert_eq!(fu.breakdown.raw_total, 20);
+            assert_eq!(fu.total, 30);
+        }
+    }
+
+    #[test]
+    fn test_fu_open_minimum_floor_is_flagged() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .open()
+            .with_winning_tile(Tile::suited(Suit::Sou, 4));
+
+        // Open pinfu shape: the breakdown keeps the true 20 base and flags the floor
+        let counts = to_counts(&parse_hand("123456m789p234s55p").unwrap());
+        let fu = calculate_fu(&decompose_hand(&counts)[0], &context);
+        assert_eq!(fu.breakdown.base, 20);
+        assert_eq!(fu.breakdown.raw_total, 20);
+        assert!(fu.breakdown.floored_to_open_minimum);
+        assert_eq!(fu.total, 30);
+
+        // A terminal triplet already reaches 30 on its own
+        let counts = to_counts(&parse_hand("111m456m789p234s55p").unwrap());
+        let fu = calculate_fu(&decompose_hand(&counts)[0], &context);
+        assert_eq!(fu.total, 30);
+        assert!(!fu.breakdown.floored_to_open_minimum);
+    }
+
     #[test]
     fn test_fu_menzen_ron() {
         // Closed hand ron = +10 fu
@@ -790,6 +1696,30 @@ mod tests {
         assert_eq!(fu.breakdown.pair, 4);
     }
 
+    #[test]
+    fn test_pair_fu_wind_matrix() {
+        // Every round/seat combination against every wind pair:
+        // 4 for double wind, 2 for either one alone, 0 for an unrelated wind
+        let winds = [Honor::East, Honor::South, Honor::West, Honor::North];
+        for round in winds {
+            for seat in winds {
+                let context = GameContext::new(WinType::Ron, round, seat);
+                for pair in winds {
+                    let expected = match (pair == round, pair == seat) {
+                        (true, true) => 4,
+                        (true, false) | (false, true) => 2,
+                        (false, false) => 0,
+                    };
+                    assert_eq!(
+                        pair_fu(Tile::honor(pair), &context),
+                        expected,
+                        "round {round:?}, seat {seat:?}, pair {pair:?}"
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_fu_wait_kanchan() {
         // Kanchan wait = 2 fu
@@ -805,6 +1735,26 @@ mod tests {
         assert_eq!(fu.breakdown.wait, 2);
     }
 
+    #[test]
+    fn test_fu_for_win_type() {
+        // Kanchan on 4s: ron 20 + 10 menzen + 2 wait = 32 -> 40,
+        // tsumo 20 + 2 tsumo + 2 wait = 24 -> 30
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 4));
+
+        let tiles = parse_hand("123m456p789s345s55p").unwrap();
+        let counts = to_counts(&tiles);
+        let structures = decompose_hand(&counts);
+        assert_eq!(structures.len(), 1);
+
+        let ron = fu_for_win_type(&structures[0], &context, WinType::Ron);
+        let tsumo = fu_for_win_type(&structures[0], &context, WinType::Tsumo);
+
+        assert_eq!(ron.total, 40);
+        assert_eq!(tsumo.total, 30);
+        assert_eq!(ron.total, tsumo.total + 10);
+    }
+
     #[test]
     fn test_fu_ron_completed_triplet_simple() {
         // When winning by ron on a shanpon wait, the triplet completed by
@@ -962,10 +1912,23 @@ mod tests {
     #[test]
     fn test_fu_rounding() {
         // Fu should round up to nearest 10
-        assert_eq!(round_up_to_10(22), 30);
-        assert_eq!(round_up_to_10(30), 30);
-        assert_eq!(round_up_to_10(31), 40);
-        assert_eq!(round_up_to_10(25), 30); // But chiitoitsu stays 25
+        assert_eq!(round_fu(22), 30);
+        assert_eq!(round_fu(30), 30);
+        assert_eq!(round_fu(31), 40);
+        assert_eq!(round_fu(25), 30); // But chiitoitsu stays 25
+    }
+
+    #[test]
+    fn test_fu_rounding_boundaries() {
+        assert_eq!(round_fu(20), 20);
+        assert_eq!(round_fu(21), 30);
+    }
+
+    #[test]
+    fn test_point_rounding_boundaries() {
+        assert_eq!(round_points(100), 100);
+        assert_eq!(round_points(101), 200);
+        assert_eq!(round_points(0), 0);
     }
 
     // ===== Kan Fu Tests =====
@@ -1004,6 +1967,32 @@ mod tests {
         assert_eq!(meld_fu(&kan_9), 16);
     }
 
+    #[test]
+    fn test_fu_added_kan_terminal_in_hand_stays_open() {
+        use crate::hand::{KanType, Meld};
+
+        // Shouminkan of 9p upgraded from a pon: open kan fu, never doubled
+        let kan = Meld::kan(Tile::suited(Suit::Pin, 9), KanType::Added);
+        let melds = vec![
+            kan.clone(),
+            Meld::shuntsu(Tile::suited(Suit::Man, 1)),
+            Meld::shuntsu(Tile::suited(Suit::Man, 4)),
+            Meld::shuntsu(Tile::suited(Suit::Sou, 7)),
+        ];
+        let structure = HandStructure::Standard {
+            melds: melds.clone(),
+            pair: Tile::suited(Suit::Sou, 5),
+        };
+
+        for win_type in [WinType::Ron, WinType::Tsumo] {
+            let context = GameContext::new(win_type, Honor::East, Honor::South)
+                .open()
+                .with_winning_tile(Tile::suited(Suit::Man, 3));
+            assert_eq!(meld_fu_with_context(&kan, &melds, &context), 16);
+            assert_eq!(calculate_fu(&structure, &context).breakdown.melds, 16);
+        }
+    }
+
     #[test]
     fn test_fu_kan_terminal_closed() {
         use crate::hand::{KanType, Meld};
@@ -1014,91 +2003,589 @@ mod tests {
     }
 
     #[test]
-    fn test_fu_kan_honor_open() {
-        use crate::hand::{KanType, Meld};
-        use crate::tile::Honor;
+    fn test_fu_kan_honor_open() {
+        use crate::hand::{KanType, Meld};
+        use crate::tile::Honor;
+
+        // Open kan of honors = 16 fu
+        let kan = Meld::kan(Tile::honor(Honor::East), KanType::Open);
+        assert_eq!(meld_fu(&kan), 16);
+
+        let dragon_kan = Meld::kan(Tile::honor(Honor::White), KanType::Added);
+        assert_eq!(meld_fu(&dragon_kan), 16);
+    }
+
+    #[test]
+    fn test_fu_kan_honor_closed() {
+        use crate::hand::{KanType, Meld};
+        use crate::tile::Honor;
+
+        // Closed kan of honors = 32 fu
+        let kan = Meld::kan(Tile::honor(Honor::Red), KanType::Closed);
+        assert_eq!(meld_fu(&kan), 32);
+
+        let wind_kan = Meld::kan(Tile::honor(Honor::North), KanType::Closed);
+        assert_eq!(meld_fu(&wind_kan), 32);
+    }
+
+    #[test]
+    fn test_fu_comparison_triplet_vs_kan() {
+        use crate::hand::{KanType, Meld};
+
+        // Kan fu should be 4x the equivalent triplet fu
+        let simple_tile = Tile::suited(Suit::Man, 5);
+        let terminal_tile = Tile::suited(Suit::Pin, 1);
+
+        // Open simple: triplet 2, kan 8
+        let triplet_open = Meld::Koutsu(simple_tile, true);
+        let kan_open = Meld::kan(simple_tile, KanType::Open);
+        assert_eq!(meld_fu(&kan_open), meld_fu(&triplet_open) * 4);
+
+        // Closed simple: triplet 4, kan 16
+        let triplet_closed = Meld::koutsu(simple_tile);
+        let kan_closed = Meld::kan(simple_tile, KanType::Closed);
+        assert_eq!(meld_fu(&kan_closed), meld_fu(&triplet_closed) * 4);
+
+        // Open terminal: triplet 4, kan 16
+        let triplet_term_open = Meld::Koutsu(terminal_tile, true);
+        let kan_term_open = Meld::kan(terminal_tile, KanType::Open);
+        assert_eq!(meld_fu(&kan_term_open), meld_fu(&triplet_term_open) * 4);
+
+        // Closed terminal: triplet 8, kan 32
+        let triplet_term_closed = Meld::koutsu(terminal_tile);
+        let kan_term_closed = Meld::kan(terminal_tile, KanType::Closed);
+        assert_eq!(meld_fu(&kan_term_closed), meld_fu(&triplet_term_closed) * 4);
+    }
+
+    // ===== Score Level Tests =====
+
+    #[test]
+    fn test_score_level_mangan() {
+        assert_eq!(determine_score_level(5, 30, false), ScoreLevel::Mangan);
+        assert_eq!(determine_score_level(4, 40, false), ScoreLevel::Mangan);
+        assert_eq!(determine_score_level(3, 70, false), ScoreLevel::Mangan);
+    }
+
+    #[test]
+    fn test_score_level_haneman() {
+        assert_eq!(determine_score_level(6, 30, false), ScoreLevel::Haneman);
+        assert_eq!(determine_score_level(7, 30, false), ScoreLevel::Haneman);
+    }
+
+    #[test]
+    fn test_score_level_baiman() {
+        assert_eq!(determine_score_level(8, 30, false), ScoreLevel::Baiman);
+        assert_eq!(determine_score_level(10, 30, false), ScoreLevel::Baiman);
+    }
+
+    #[test]
+    fn test_score_level_sanbaiman() {
+        assert_eq!(determine_score_level(11, 30, false), ScoreLevel::Sanbaiman);
+        assert_eq!(determine_score_level(12, 30, false), ScoreLevel::Sanbaiman);
+    }
+
+    #[test]
+    fn test_score_level_yakuman() {
+        assert_eq!(determine_score_level(13, 30, false), ScoreLevel::Yakuman);
+        assert_eq!(determine_score_level(13, 30, true), ScoreLevel::Yakuman);
+    }
+
+    #[test]
+    fn test_chiitoitsu_25_fu_with_high_han() {
+        // Chiitoitsu 2 + chinitsu 6 = baiman, still at an unrounded 25 fu
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        let results = score_hand("11224466778899m", &context);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].fu.total, 25);
+        assert_eq!(results[0].han, 8);
+        assert_eq!(results[0].score_level, ScoreLevel::Baiman);
+
+        // 4 han 25 fu is 1600 basic points, short of mangan; 4 han needs 40 fu
+        assert_eq!(determine_score_level(4, 25, false), ScoreLevel::Normal);
+        assert_eq!(calculate_basic_points(4, 25, false), 1600);
+        assert_eq!(determine_score_level(4, 40, false), ScoreLevel::Mangan);
+        assert_eq!(determine_score_level(5, 25, false), ScoreLevel::Mangan);
+    }
+
+    #[test]
+    fn test_score_level_with_rules() {
+        let defaults = YakuRules::default();
+        assert_eq!(
+            determine_score_level_with_rules(4, 30, false, &defaults),
+            ScoreLevel::Normal
+        );
+
+        let rules = YakuRules {
+            kazoe_yakuman: false,
+            kiriage: true,
+            ..YakuRules::default()
+        };
+        assert_eq!(
+            determine_score_level_with_rules(4, 30, false, &rules),
+            ScoreLevel::Mangan
+        );
+        assert_eq!(
+            determine_score_level_with_rules(3, 60, false, &rules),
+            ScoreLevel::Mangan
+        );
+        assert_eq!(
+            determine_score_level_with_rules(3, 50, false, &rules),
+            ScoreLevel::Normal
+        );
+        // Counted yakuman capped at sanbaiman, real yakuman unaffected
+        assert_eq!(
+            determine_score_level_with_rules(13, 30, false, &rules),
+            ScoreLevel::Sanbaiman
+        );
+        assert_eq!(
+            determine_score_level_with_rules(13, 30, true, &rules),
+            ScoreLevel::Yakuman
+        );
+    }
+
+    #[test]
+    fn test_score_under_yaku_rules() {
+        // Riichi + pinfu + tanyao + 1 dora = 4 han 30 fu
+        let base = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi()
+            .with_dora(vec![Tile::suited(Suit::Man, 1)]);
+
+        let default_score = best_score(&score_hand("234567m234p345s88s", &base)).clone();
+        assert_eq!(default_score.han, 4);
+        assert_eq!(default_score.fu.total, 30);
+        assert_eq!(default_score.payment.total, 7700);
+
+        let rules = YakuRules {
+            kiriage: true,
+            ..YakuRules::default()
+        };
+        let kiriage_context = base.clone().with_rules(rules);
+        let kiriage_score = best_score(&score_hand("234567m234p345s88s", &kiriage_context)).clone();
+        assert_eq!(kiriage_score.score_level, ScoreLevel::Mangan);
+        assert_eq!(kiriage_score.payment.total, 8000);
+    }
+
+    #[test]
+    fn test_kiriage_rounds_3_han_60_fu() {
+        // Riichi + sanankou with three terminal triplets = 3 han 60 fu
+        let base = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 3))
+            .riichi();
+
+        let default_score = best_score(&score_hand("111m999p111s345s55p", &base)).clone();
+        assert_eq!(default_score.han, 3);
+        assert_eq!(default_score.fu.total, 60);
+        assert_eq!(default_score.score_level, ScoreLevel::Normal);
+        assert_eq!(default_score.payment.total, 7700);
+
+        let strict = base.clone().with_kiriage_mangan(false);
+        let strict_score = best_score(&score_hand("111m999p111s345s55p", &strict)).clone();
+        assert_eq!(strict_score.score_level, ScoreLevel::Normal);
+
+        let kiriage = base.clone().with_kiriage_mangan(true);
+        let kiriage_score = best_score(&score_hand("111m999p111s345s55p", &kiriage)).clone();
+        assert_eq!(kiriage_score.score_level, ScoreLevel::Mangan);
+        assert_eq!(kiriage_score.payment.total, 8000);
+    }
+
+    #[test]
+    fn test_score_with_assumed_ura() {
+        // Riichi + pinfu + tanyao = 3 han 30 fu
+        let tiles = parse_hand("234567m234p345s88s").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).riichi();
+        let winning_tile = Tile::suited(Suit::Man, 2);
+
+        let no_ura = score_with_assumed_ura(&counts, &context, winning_tile, 0).unwrap();
+        assert_eq!(no_ura.han, 3);
+        assert_eq!(no_ura.payment.total, 3900);
+
+        let two_ura = score_with_assumed_ura(&counts, &context, winning_tile, 2).unwrap();
+        assert_eq!(two_ura.han, 5);
+        assert_eq!(two_ura.score_level, ScoreLevel::Mangan);
+        assert_eq!(two_ura.payment.total, 8000);
+
+        // An incomplete hand has nothing to score
+        let tenpai = to_counts(&parse_hand("234567m234p345s8s").unwrap());
+        assert!(score_with_assumed_ura(&tenpai, &context, winning_tile, 1).is_none());
+    }
+
+    #[test]
+    fn test_top_two_interpretations_ryanpeikou_over_chiitoitsu() {
+        // Riichi + pinfu + ryanpeikou = 5 han beats riichi + chiitoitsu = 3 han 25 fu
+        let counts = to_counts(&parse_hand("223344m556677p99s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+
+        let (best, runner_up) = top_two_interpretations(&counts, &context).unwrap();
+        assert_eq!(best.han, 5);
+        assert_eq!(best.payment.total, 8000);
+
+        let runner_up = runner_up.expect("chiitoitsu interpretation");
+        assert_eq!(runner_up.han, 3);
+        assert_eq!(runner_up.fu.total, 25);
+        assert_eq!(runner_up.payment.total, 3200);
+    }
+
+    #[test]
+    fn test_top_two_interpretations_single_structure() {
+        let counts = to_counts(&parse_hand("234567m234p345s88s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+
+        let (best, runner_up) = top_two_interpretations(&counts, &context).unwrap();
+        assert_eq!(best.han, 3);
+        assert!(runner_up.is_none());
+
+        let incomplete = to_counts(&parse_hand("234567m234p345s8s").unwrap());
+        assert!(top_two_interpretations(&incomplete, &context).is_none());
+    }
+
+    #[test]
+    fn test_disabled_menzen_tsumo_keeps_tsumo_fu() {
+        // Riichi + haku + menzen tsumo, tanki on 2s: not pinfu, so tsumo fu applies
+        let standard = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 2))
+            .riichi();
+        let house = standard.clone().disable_yaku(Yaku::MenzenTsumo);
+
+        let with_tsumo = best_score(&score_hand("123m456p789s555z22s", &standard)).clone();
+        let without_tsumo = best_score(&score_hand("123m456p789s555z22s", &house)).clone();
+
+        assert_eq!(with_tsumo.han, 3);
+        assert_eq!(without_tsumo.han, 2);
+        assert_eq!(without_tsumo.fu.breakdown.tsumo, 2);
+        assert_eq!(without_tsumo.fu.total, with_tsumo.fu.total);
+    }
+
+    #[test]
+    fn test_seat_deltas_dealer_ron_with_honba() {
+        // Dealer riichi + pinfu + tanyao, 3 han 30 fu = 5800, dealt in by seat 2
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::East)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+        let score = best_score(&score_hand("234567m234p345s88s", &context)).clone();
+        assert_eq!(score.payment.total, 5800);
+
+        assert_eq!(
+            seat_deltas(&score, 0, Some(2), 0, 1, 0),
+            Ok([6100, 0, -6100, 0])
+        );
+
+        // Honba already in the score's own payment is not counted twice, and must
+        // agree with the table's count
+        let with_honba =
+            best_score(&score_hand("234567m234p345s88s", &context.with_honba(1))).clone();
+        assert_eq!(with_honba.payment.total, 6100);
+        assert_eq!(
+            seat_deltas(&with_honba, 0, Some(2), 0, 1, 0),
+            Ok([6100, 0, -6100, 0])
+        );
+        assert_eq!(
+            seat_deltas(&with_honba, 0, Some(2), 0, 2, 0),
+            Err(SeatError::HonbaMismatch {
+                in_score: 1,
+                given: 2
+            })
+        );
+
+        // The dealer seat rotates: seat 2 deals and wins off seat 3
+        assert_eq!(
+            seat_deltas(&score, 2, Some(3), 2, 1, 0),
+            Ok([0, 0, 6100, -6100])
+        );
+    }
+
+    #[test]
+    fn test_seat_deltas_non_dealer_tsumo_with_sticks() {
+        // Riichi + tsumo + pinfu + tanyao, 4 han 20 fu = 1300/2600
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+        let score = best_score(&score_hand("234567m234p345s88s", &context)).clone();
+        assert_eq!(score.payment.from_dealer, Some(2600));
+        assert_eq!(score.payment.from_non_dealer, Some(1300));
+
+        let deltas = seat_deltas(&score, 1, None, 0, 0, 2);
+        assert_eq!(deltas, Ok([-2600, 7200, -1300, -1300]));
+
+        // With seat 3 dealing, seat 3 pays the dealer share
+        let deltas = seat_deltas(&score, 1, None, 3, 0, 2);
+        assert_eq!(deltas, Ok([-1300, 7200, -1300, -2600]));
+    }
+
+    #[test]
+    fn test_seat_deltas_rejects_invalid_seats() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+        let score = best_score(&score_hand("234567m234p345s88s", &context)).clone();
+
+        assert!(seat_deltas(&score, 1, Some(2), 0, 0, 0).is_ok());
+        // Ron without a discarder, or dealt in by the winner
+        assert_eq!(
+            seat_deltas(&score, 1, None, 0, 0, 0),
+            Err(SeatError::MissingDiscarder)
+        );
+        assert_eq!(
+            seat_deltas(&score, 1, Some(1), 0, 0, 0),
+            Err(SeatError::InvalidDiscarder(1))
+        );
+        // Seats out of range, or a non-dealer score in the dealer's seat
+        assert_eq!(
+            seat_deltas(&score, 4, Some(2), 0, 0, 0),
+            Err(SeatError::WinnerOutOfRange(4))
+        );
+        assert_eq!(
+            seat_deltas(&score, 1, Some(7), 0, 0, 0),
+            Err(SeatError::InvalidDiscarder(7))
+        );
+        assert_eq!(
+            seat_deltas(&score, 1, Some(2), 4, 0, 0),
+            Err(SeatError::DealerOutOfRange(4))
+        );
+        assert!(matches!(
+            seat_deltas(&score, 0, Some(2), 0, 0, 0),
+            Err(SeatError::DealerMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_prefer_pinfu_toggle_on_ambiguous_wait() {
+        // 4m completes 456m as ryanmen or 345m as kanchan
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 4));
 
-        // Open kan of honors = 16 fu
-        let kan = Meld::kan(Tile::honor(Honor::East), KanType::Open);
-        assert_eq!(meld_fu(&kan), 16);
+        // Pinfu + tanyao, 2 han 30 fu
+        let pinfu = best_score(&score_hand("344556m234p678s88s", &context)).clone();
+        assert_eq!(pinfu.han, 2);
+        assert_eq!(pinfu.fu.total, 30);
+        assert_eq!(pinfu.payment.total, 2000);
 
-        let dragon_kan = Meld::kan(Tile::honor(Honor::White), KanType::Added);
-        assert_eq!(meld_fu(&dragon_kan), 16);
+        // Tanyao with kanchan fu, 1 han 40 fu
+        let max_fu = context.no_prefer_pinfu();
+        let kanchan = best_score(&score_hand("344556m234p678s88s", &max_fu)).clone();
+        assert_eq!(kanchan.han, 1);
+        assert_eq!(kanchan.fu.breakdown.wait, 2);
+        assert_eq!(kanchan.fu.total, 40);
+        assert_eq!(kanchan.payment.total, 1300);
     }
 
     #[test]
-    fn test_fu_kan_honor_closed() {
-        use crate::hand::{KanType, Meld};
-        use crate::tile::Honor;
+    fn test_assumed_ura_keeps_the_scorers_wait_reading() {
+        // With the pinfu yaku disabled the kanchan reading pays more (see below)
+        let counts = to_counts(&parse_hand("344556m234p678s88s").unwrap());
+        let winning_tile = Tile::suited(Suit::Man, 4);
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(winning_tile)
+            .disable_yaku(Yaku::Pinfu);
 
-        // Closed kan of honors = 32 fu
-        let kan = Meld::kan(Tile::honor(Honor::Red), KanType::Closed);
-        assert_eq!(meld_fu(&kan), 32);
+        let best = best_score_for_counts(&counts, &context, false).unwrap();
+        let no_ura = score_with_assumed_ura(&counts, &context, winning_tile, 0).unwrap();
+        assert_eq!(no_ura.payment.total, best.payment.total);
+        assert_eq!(no_ura.fu.total, best.fu.total);
+        assert_eq!(no_ura.han, best.han);
+        assert_eq!(no_ura.payment.total, 2000);
 
-        let wind_kan = Meld::kan(Tile::honor(Honor::North), KanType::Closed);
-        assert_eq!(meld_fu(&wind_kan), 32);
+        let one_ura = score_with_assumed_ura(&counts, &context, winning_tile, 1).unwrap();
+        assert_eq!(one_ura.han, best.han + 1);
+        assert_eq!(one_ura.fu.total, 30);
     }
 
     #[test]
-    fn test_fu_comparison_triplet_vs_kan() {
-        use crate::hand::{KanType, Meld};
-
-        // Kan fu should be 4x the equivalent triplet fu
-        let simple_tile = Tile::suited(Suit::Man, 5);
-        let terminal_tile = Tile::suited(Suit::Pin, 1);
+    fn test_prefer_pinfu_keeps_higher_paying_wait_reading() {
+        // Without the pinfu yaku, the ryanmen reading of a tsumo is left at 20 fu
+        // while the kanchan reading scores 30 fu
+        let counts = to_counts(&parse_hand("344556m234p678s88s").unwrap());
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 4))
+            .disable_yaku(Yaku::Pinfu);
 
-        // Open simple: triplet 2, kan 8
-        let triplet_open = Meld::Koutsu(simple_tile, true);
-        let kan_open = Meld::kan(simple_tile, KanType::Open);
-        assert_eq!(meld_fu(&kan_open), meld_fu(&triplet_open) * 4);
+        let ryanmen = best_score(&score_hand("344556m234p678s88s", &context)).clone();
+        assert_eq!(ryanmen.fu.total, 20);
+        assert_eq!(ryanmen.payment.total, 1500);
 
-        // Closed simple: triplet 4, kan 16
-        let triplet_closed = Meld::koutsu(simple_tile);
-        let kan_closed = Meld::kan(simple_tile, KanType::Closed);
-        assert_eq!(meld_fu(&kan_closed), meld_fu(&triplet_closed) * 4);
+        // Menzen tsumo + tanyao, 2 han 30 fu
+        let best = best_score_for_counts(&counts, &context, true).unwrap();
+        assert_eq!(best.han, 2);
+        assert_eq!(best.fu.breakdown.wait, 2);
+        assert_eq!(best.fu.total, 30);
+        assert_eq!(best.payment.total, 2000);
 
-        // Open terminal: triplet 4, kan 16
-        let triplet_term_open = Meld::Koutsu(terminal_tile, true);
-        let kan_term_open = Meld::kan(terminal_tile, KanType::Open);
-        assert_eq!(meld_fu(&kan_term_open), meld_fu(&triplet_term_open) * 4);
+        // With the pinfu yaku the ryanmen reading still pays more
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 4));
+        let best = best_score_for_counts(&counts, &context, true).unwrap();
+        assert_eq!(best.han, 3);
+        assert_eq!(best.fu.total, 20);
+    }
 
-        // Closed terminal: triplet 8, kan 32
-        let triplet_term_closed = Meld::koutsu(terminal_tile);
-        let kan_term_closed = Meld::kan(terminal_tile, KanType::Closed);
-        assert_eq!(meld_fu(&kan_term_closed), meld_fu(&triplet_term_closed) * 4);
+    #[test]
+    fn test_scoring_result_display_mangan_ron() {
+        // Riichi + pinfu + tanyao + 2 dora
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi()
+            .with_dora(vec![Tile::suited(Suit::Man, 1), Tile::suited(Suit::Pin, 1)]);
+        let score = best_score(&score_hand("234567m234p345s88s", &context)).clone();
+        assert_eq!(score.to_string(), "5han/30fu Mangan 8000 (ron)");
     }
 
-    // ===== Score Level Tests =====
+    #[test]
+    fn test_scoring_result_display_dealer_tsumo() {
+        // Riichi + tsumo + pinfu + tanyao, 4 han 20 fu
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::East)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+        let score = best_score(&score_hand("234567m234p345s88s", &context)).clone();
+        assert_eq!(score.to_string(), "4han/20fu 7800 (tsumo 2600 all)");
+    }
 
     #[test]
-    fn test_score_level_mangan() {
-        assert_eq!(determine_score_level(5, 30, false), ScoreLevel::Mangan);
-        assert_eq!(determine_score_level(4, 40, false), ScoreLevel::Mangan);
-        assert_eq!(determine_score_level(3, 70, false), ScoreLevel::Mangan);
+    fn test_scoring_result_display_counted_yakuman() {
+        // Riichi + pinfu + ryanpeikou + chinitsu + 2 dora = 13 han
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 2))
+            .riichi()
+            .with_dora(vec![Tile::suited(Suit::Sou, 1)]);
+        let score = best_score(&score_hand("22334455667799s", &context)).clone();
+        assert_eq!(score.to_string(), "13han/30fu Counted Yakuman 32000 (ron)");
     }
 
     #[test]
-    fn test_score_level_haneman() {
-        assert_eq!(determine_score_level(6, 30, false), ScoreLevel::Haneman);
-        assert_eq!(determine_score_level(7, 30, false), ScoreLevel::Haneman);
+    fn test_custom_mangan_limit_scales_payouts() {
+        // Riichi + pinfu + tanyao + 2 dora = mangan
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi()
+            .with_dora(vec![Tile::suited(Suit::Man, 1), Tile::suited(Suit::Pin, 1)]);
+        let standard = best_score(&score_hand("234567m234p345s88s", &context)).clone();
+        assert_eq!(standard.score_level, ScoreLevel::Mangan);
+        assert_eq!(standard.payment.total, 8000);
+
+        let limits = ScoreLimits {
+            mangan: 2200,
+            ..ScoreLimits::default()
+        };
+        let custom_context = context.with_limits(limits);
+        let custom = best_score(&score_hand("234567m234p345s88s", &custom_context)).clone();
+        assert_eq!(custom.basic_points, 2200);
+        assert_eq!(custom.payment.total, 8800);
+
+        // Other limits keep their defaults
+        assert_eq!(ScoreLevel::Haneman.basic_points_with_limits(&limits), 3000);
+        assert_eq!(
+            ScoreLevel::DoubleYakuman.basic_points_with_limits(&limits),
+            16000
+        );
     }
 
     #[test]
-    fn test_score_level_baiman() {
-        assert_eq!(determine_score_level(8, 30, false), ScoreLevel::Baiman);
-        assert_eq!(determine_score_level(10, 30, false), ScoreLevel::Baiman);
+    fn test_compare_tenpai_value() {
+        // A can reach ittsu + pinfu + tsumo; B tops out at menzen tsumo
+        let a = to_counts(&parse_hand("23456789m123p11s").unwrap());
+        let b = to_counts(&parse_hand("123m456p789s1112z").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        assert_eq!(compare_tenpai_value(&a, &b, &context), Ordering::Greater);
+        assert_eq!(compare_tenpai_value(&b, &a, &context), Ordering::Less);
+        assert_eq!(compare_tenpai_value(&a, &a, &context), Ordering::Equal);
     }
 
     #[test]
-    fn test_score_level_sanbaiman() {
-        assert_eq!(determine_score_level(11, 30, false), ScoreLevel::Sanbaiman);
-        assert_eq!(determine_score_level(12, 30, false), ScoreLevel::Sanbaiman);
+    fn test_compare_tenpai_value_with_melds() {
+        // Open tanyao after a 234p chi: 1000 on ron, below a closed menzen tsumo
+        // but above a hand that isn't tenpai
+        let open = to_counts(&parse_hand("23m567p678s55s").unwrap());
+        let open_melds = [Meld::shuntsu_open(Tile::suited(Suit::Pin, 2))];
+        let closed = to_counts(&parse_hand("123m456p789s3334z").unwrap());
+        let noten = to_counts(&parse_hand("13579m13579p135s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        assert_eq!(
+            compare_tenpai_value_with_melds(&open, &open_melds, &closed, &[], &context),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_tenpai_value_with_melds(&open, &open_melds, &noten, &[], &context),
+            Ordering::Greater
+        );
     }
 
     #[test]
-    fn test_score_level_yakuman() {
-        assert_eq!(determine_score_level(13, 30, false), ScoreLevel::Yakuman);
-        assert_eq!(determine_score_level(13, 30, true), ScoreLevel::Yakuman);
+    fn test_score_summary_from_interpretation() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi()
+            .with_dora(vec![Tile::suited(Suit::Man, 1)]);
+        let counts = to_counts(&parse_hand("234567m234p345s88s").unwrap());
+        let structure = &decompose_hand(&counts)[0];
+        let yaku_result = detect_yaku_with_context(structure, &counts, &context);
+        let score = calculate_score(structure, &yaku_result, &context);
+
+        let summary = ScoreSummary::from((structure, &yaku_result, &score, &context));
+
+        assert_eq!(&summary.structure, structure);
+        assert_eq!(summary.yaku_han, 3);
+        assert_eq!(summary.total_han, 4);
+        assert_eq!(summary.dora.total(), 1);
+        assert_eq!(summary.fu.total, 30);
+        assert_eq!(summary.payment.total, 7700);
+        assert_eq!(summary.score_level_name(), "");
+        assert_eq!(summary.winning_tile, Some(Tile::suited(Suit::Man, 2)));
+        assert!(summary.yaku.contains(&(Yaku::Riichi, 1)));
+    }
+
+    #[test]
+    fn test_fu_to_mangan() {
+        assert_eq!(fu_to_mangan(4, 30), Some(10));
+        assert_eq!(fu_to_mangan(3, 40), Some(30));
+        assert_eq!(fu_to_mangan(5, 30), None);
+        // Already mangan at 4 han 40 fu
+        assert_eq!(fu_to_mangan(4, 40), None);
+        // 2 han can't reach mangan through fu alone
+        assert_eq!(fu_to_mangan(2, 30), None);
+    }
+
+    #[test]
+    fn test_teaching_points() {
+        assert_eq!(teaching_points(0, false), 0);
+        assert_eq!(teaching_points(1, false), 1000);
+        assert_eq!(teaching_points(2, false), 2000);
+        assert_eq!(teaching_points(3, false), 3000);
+        assert_eq!(teaching_points(4, false), 4000);
+        assert_eq!(teaching_points(5, false), 8000); // Mangan
+        assert!(teaching_points(4, false) < teaching_points(5, false));
+        assert_eq!(teaching_points(6, false), 12000); // Haneman
+        assert_eq!(teaching_points(13, false), 32000);
+
+        assert_eq!(teaching_points(1, true), 1500);
+        assert_eq!(teaching_points(3, true), 4500);
+        assert!(teaching_points(4, true) < teaching_points(5, true));
+        assert_eq!(teaching_points(5, true), 12000);
+        assert_eq!(teaching_points(8, true), 24000); // Baiman
+        assert_eq!(teaching_points(13, true), 48000);
+    }
+
+    #[test]
+    fn test_dora_to_next_level() {
+        // 4 han 30 fu is just below mangan
+        assert_eq!(dora_to_next_level(4, 30), 1);
+        // 1 han 30 fu: Normal -> Mangan needs 5 han (4 han 30 fu isn't mangan)
+        assert_eq!(dora_to_next_level(1, 30), 4);
+        // 3 han 40 fu -> 4 han 40 fu is mangan
+        assert_eq!(dora_to_next_level(3, 40), 1);
+        // Mangan -> Haneman
+        assert_eq!(dora_to_next_level(5, 30), 1);
+        // Haneman (6) -> Baiman (8)
+        assert_eq!(dora_to_next_level(6, 30), 2);
+        // Sanbaiman (11) -> counted Yakuman (13)
+        assert_eq!(dora_to_next_level(11, 30), 2);
+        // Counted yakuman is the ceiling
+        assert_eq!(dora_to_next_level(13, 30), 0);
     }
 
     // ===== Basic Points Tests =====
@@ -1175,6 +2662,74 @@ mod tests {
         assert_eq!(payment.total, 8000);
     }
 
+    #[test]
+    fn test_nagashi_mangan() {
+        let discards = parse_hand("19m1p99s1234567z").unwrap();
+
+        let result = calculate_nagashi_mangan(&discards, false).unwrap();
+        assert_eq!(result.score_level, ScoreLevel::Mangan);
+        assert_eq!(result.payment.total, 8000);
+        assert_eq!(result.payment.from_dealer, Some(4000));
+        assert_eq!(result.payment.from_non_dealer, Some(2000));
+
+        let result = calculate_nagashi_mangan(&discards, true).unwrap();
+        assert_eq!(result.payment.total, 12000);
+        assert_eq!(result.payment.from_non_dealer, Some(4000));
+
+        let with_simple = parse_hand("19m5p99s1234567z").unwrap();
+        assert!(calculate_nagashi_mangan(&with_simple, false).is_none());
+        assert!(calculate_nagashi_mangan(&[], false).is_none());
+    }
+
+    #[test]
+    fn test_payment_dealer_ron_two_honba() {
+        // Dealer ron mangan 12000 + 2 × 300
+        let payment = calculate_payment_with_honba(2000, true, WinType::Ron, 2);
+
+        assert_eq!(payment.from_discarder, Some(12600));
+        assert_eq!(payment.total, 12600);
+    }
+
+    #[test]
+    fn test_payment_non_dealer_tsumo_one_honba() {
+        // Non-dealer tsumo mangan 4000/2000, +100 from each payer
+        let payment = calculate_payment_with_honba(2000, false, WinType::Tsumo, 1);
+
+        assert_eq!(payment.from_dealer, Some(4100));
+        assert_eq!(payment.from_non_dealer, Some(2100));
+        assert_eq!(payment.total, 8300);
+        assert_eq!(
+            payment.total,
+            payment.from_dealer.unwrap() + 2 * payment.from_non_dealer.unwrap()
+        );
+    }
+
+    #[test]
+    fn test_calculate_score_uses_context_honba() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .riichi()
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .with_honba(2);
+        let results = score_hand("223344m345p678s55p", &context);
+
+        // Riichi + pinfu + tanyao + iipeikou: 4 han 30 fu = 7700, + 600
+        assert_eq!(best_score(&results).payment.total, 8300);
+    }
+
+    #[test]
+    fn test_riichi_sticks_added_outside_payment() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .riichi()
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .with_riichi_sticks(2);
+        let results = score_hand("223344m345p678s55p", &context);
+        let best = best_score(&results);
+
+        // 4 han 30 fu = 7700 from the discarder, plus 2000 in deposits
+        assert_eq!(best.payment.total, 7700);
+        assert_eq!(best.total_with_sticks, 9700);
+    }
+
     #[test]
     fn test_payment_rounding() {
         // Payments round up to nearest 100
@@ -1215,6 +2770,69 @@ mod tests {
         assert_eq!(best.han, 2);
     }
 
+    #[test]
+    fn test_complete_score_pinfu_tsumo_haitei() {
+        // Pinfu + Menzen Tsumo + Haitei = 3 han, still 20 fu
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 4)) // Ryanmen
+            .last_tile();
+
+        let tiles = parse_hand("123456m789p234s55p").unwrap();
+        let counts = to_counts(&tiles);
+        let structures = decompose_hand(&counts);
+        let yaku_result = detect_yaku_with_context(&structures[0], &counts, &context);
+
+        assert!(
+            yaku_result
+                .yaku_list
+                .contains(&crate::yaku::Yaku::MenzenTsumo)
+        );
+        assert!(yaku_result.yaku_list.contains(&crate::yaku::Yaku::Pinfu));
+        assert!(
+            yaku_result
+                .yaku_list
+                .contains(&crate::yaku::Yaku::HaiteiRaoyue)
+        );
+
+        let score = calculate_score(&structures[0], &yaku_result, &context);
+        assert_eq!(score.fu.total, 20);
+        assert_eq!(score.han, 3);
+    }
+
+    #[test]
+    fn test_complete_score_pinfu_tsumo_haitei_with_dora() {
+        // Dora indicator 4p makes the 55p pair worth 2 dora: 3 + 2 = 5 han
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Sou, 4))
+            .last_tile()
+            .with_dora(vec![Tile::suited(Suit::Pin, 4)]);
+
+        let results = score_hand("123456m789p234s55p", &context);
+        let best = best_score(&results);
+
+        assert_eq!(best.fu.total, 20);
+        assert_eq!(best.han, 5);
+        assert_eq!(best.score_level, ScoreLevel::Mangan);
+    }
+
+    #[test]
+    fn test_open_all_sequence_hand_no_pinfu_fu() {
+        // Open ittsu with a ryanmen wait: no pinfu, open hand floors at 30 fu
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .open()
+            .with_winning_tile(Tile::suited(Suit::Sou, 4));
+
+        let tiles = parse_hand("123456789m234s55p").unwrap();
+        let counts = to_counts(&tiles);
+        for structure in decompose_hand(&counts) {
+            let yaku_result = detect_yaku_with_context(&structure, &counts, &context);
+            assert!(!yaku_result.yaku_list.contains(&crate::yaku::Yaku::Pinfu));
+
+            let score = calculate_score(&structure, &yaku_result, &context);
+            assert_eq!(score.fu.total, 30);
+        }
+    }
+
     #[test]
     fn test_complete_score_haneman() {
         // Chinitsu (6 han closed) + Menzen Tsumo (1 han) = 7 han = Haneman
@@ -1442,6 +3060,42 @@ mod tests {
         assert!(!best.is_counted_yakuman); // True yakuman, not counted
     }
 
+    #[test]
+    fn test_kokushi_has_no_standard_interpretation() {
+        let tiles = parse_hand("19m19p19s12345677z").unwrap();
+        let counts = to_counts(&tiles);
+
+        assert_eq!(
+            decompose_hand(&counts),
+            vec![HandStructure::Kokushi {
+                pair: Tile::Honor(Honor::Red)
+            }]
+        );
+
+        // Every winning tile choice still scores as a real yakuman
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        for tile in tiles {
+            let best =
+                best_score_for_counts(&counts, &context.clone().with_winning_tile(tile), true)
+                    .unwrap();
+            // Winning on the paired tile is the 13-sided wait (double yakuman)
+            assert!(best.score_level >= ScoreLevel::Yakuman);
+            assert!(!best.is_counted_yakuman);
+        }
+    }
+
+    #[test]
+    fn test_max_possible_score_kokushi_tenpai() {
+        // Single wait on 7z
+        let counts = to_counts(&parse_hand("19m19p19s1234566z").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        let (tile, _, score) = max_possible_score(&counts, &context).unwrap();
+        assert_eq!(tile, Tile::Honor(Honor::Red));
+        assert_eq!(score.score_level, ScoreLevel::Yakuman);
+        assert!(!score.is_counted_yakuman);
+    }
+
     #[test]
     fn test_true_yakuman_suuankou() {
         // Suuankou - four concealed triplets (true yakuman)
@@ -1469,6 +3123,35 @@ mod tests {
         assert!(!best.is_counted_yakuman); // True yakuman, not counted
     }
 
+    #[test]
+    fn test_daisuushii_tsuuiisou_is_double_yakuman() {
+        // Four wind triplets with an honor pair: daisuushii + tsuuiisou stack.
+        // The open pon keeps suuankou out of the picture.
+        use crate::hand::decompose_hand_with_melds;
+        use crate::parse::parse_hand_with_aka;
+
+        let parsed = parse_hand_with_aka("222333444z77z(111z)").unwrap();
+        let counts = parsed.concealed_counts();
+        let called_melds: Vec<_> = parsed
+            .called_melds
+            .iter()
+            .map(|cm| cm.meld.clone())
+            .collect();
+        let structure = &decompose_hand_with_melds(&counts, &called_melds)[0];
+
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::East)
+            .open()
+            .with_winning_tile(Tile::honor(Honor::Red));
+        let yaku_result = detect_yaku_with_context(structure, &parsed.all_counts(), &context);
+        assert!(yaku_result.yaku_list.contains(&Yaku::Daisuushii));
+        assert!(yaku_result.yaku_list.contains(&Yaku::Tsuuiisou));
+
+        let result = calculate_score(structure, &yaku_result, &context);
+        assert_eq!(result.score_level, ScoreLevel::DoubleYakuman);
+        assert_eq!(result.payment.from_non_dealer, Some(32000));
+        assert_eq!(result.payment.total, 96000);
+    }
+
     #[test]
     fn test_not_counted_yakuman_below_13_han() {
         // A high-scoring hand that doesn't reach yakuman level
@@ -2109,6 +3792,164 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_score_both_win_types() {
+        // Pinfu + Tanyao on ron; tsumo adds Menzen Tsumo
+        let tiles = parse_hand("234567m234567p22s").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        let (tsumo, ron) =
+            score_both_win_types(&counts, &context, Tile::suited(Suit::Man, 7)).unwrap();
+
+        assert_eq!(tsumo.han, ron.han + 1);
+        assert_eq!(tsumo.fu.total, 20); // Pinfu tsumo
+        assert_eq!(ron.fu.total, 30); // Pinfu ron
+        assert!(ron.payment.from_discarder.is_some());
+        assert!(tsumo.payment.from_discarder.is_none());
+
+        // Not a complete hand
+        let counts = to_counts(&parse_hand("13579m13579p1357s").unwrap());
+        assert!(score_both_win_types(&counts, &context, Tile::suited(Suit::Man, 1)).is_none());
+    }
+
+    #[test]
+    fn test_tsumo_only() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        // Closed hand with no pattern yaku: only menzen tsumo can give it a yaku
+        let counts = to_counts(&parse_hand("123m456p789s44433z").unwrap());
+        assert!(tsumo_only(&counts, &context, Tile::honor(Honor::West)));
+
+        // Pinfu + tanyao has yaku either way
+        let counts = to_counts(&parse_hand("234567m234567p22s").unwrap());
+        assert!(!tsumo_only(&counts, &context, Tile::suited(Suit::Man, 7)));
+    }
+
+    #[test]
+    fn test_score_with_max_aka() {
+        // Pinfu + tanyao + sanshoku with a five in every suit
+        let counts = to_counts(&parse_hand("345m345p345s678m22p").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        let winning_tile = Tile::suited(Suit::Man, 8);
+
+        let baseline = best_score_for_counts(
+            &counts,
+            &context.clone().with_winning_tile(winning_tile),
+            false,
+        )
+        .unwrap();
+        let max_aka = score_with_max_aka(&counts, &context, winning_tile).unwrap();
+
+        assert_eq!(baseline.han, 4);
+        assert_eq!(max_aka.han, baseline.han + 3);
+        assert_eq!(max_aka.score_level, ScoreLevel::Haneman);
+
+        // Not a complete hand
+        let counts = to_counts(&parse_hand("13579m13579p1357s").unwrap());
+        assert!(score_with_max_aka(&counts, &context, Tile::suited(Suit::Man, 1)).is_none());
+    }
+
+    #[test]
+    fn test_fu_by_winning_tile() {
+        // 3334m waits on 2m/5m as a ryanmen (pinfu, 30 fu) or on 4m as a tanki
+        // next to a concealed 333m (20 + 10 + 4 + 2 = 36, rounded to 40)
+        let counts = to_counts(&parse_hand("3334m123p456s789s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        assert_eq!(
+            fu_by_winning_tile(&counts, &context),
+            vec![
+                (Tile::suited(Suit::Man, 2), 30),
+                (Tile::suited(Suit::Man, 4), 40),
+                (Tile::suited(Suit::Man, 5), 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kan_impact_loses_pinfu() {
+        // 111222333m reads as three 123m for pinfu on the 3p/6p wait; a kan on 1m
+        // locks in the triplets: 32 (closed terminal kan) + 4 + 4 + 10 + 20 = 70 fu,
+        // up from 50 fu for the sanankou reading
+        let counts = to_counts(&parse_hand("111222333m45p66s").unwrap());
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        let impact = kan_impact(&counts, Tile::suited(Suit::Man, 1), &context);
+        assert!(impact.loses_pinfu);
+        assert_eq!(impact.shanten_change, 0);
+        assert_eq!(impact.fu_change, 20);
+    }
+
+    #[test]
+    fn test_riichi_ev_prefers_bigger_hand() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        // Riichi + pinfu + tanyao on a 2s/5s wait
+        let cheap = to_counts(&parse_hand("234567m234p34s55p").unwrap());
+        // Riichi + chinitsu (+ ittsu on some waits)
+        let big = to_counts(&parse_hand("1234567891234m").unwrap());
+
+        let cheap_ev = riichi_ev(&cheap, &context).unwrap();
+        let big_ev = riichi_ev(&big, &context).unwrap();
+        assert!(cheap_ev > 3900.0, "cheap EV {cheap_ev}");
+        assert!(big_ev > cheap_ev, "big EV {big_ev} vs cheap EV {cheap_ev}");
+    }
+
+    #[test]
+    fn test_riichi_ev_none_when_riichi_impossible() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        // Not tenpai
+        let noten = to_counts(&parse_hand("1357m2468p1357s1z").unwrap());
+        assert_eq!(riichi_ev(&noten, &context), None);
+
+        // Tenpai after a call: 10 concealed tiles, and an open context
+        let open = to_counts(&parse_hand("23m567p678s55s").unwrap());
+        assert_eq!(riichi_ev(&open, &context), None);
+        assert_eq!(riichi_ev(&open, &context.clone().open()), None);
+    }
+
+    #[test]
+    fn test_max_possible_score_tsumo_on_ittsu_tile() {
+        // 23456789m123p11s: waits 1m (ittsu), 4m, 7m
+        // Best: tsumo on 1m = Menzen Tsumo + Pinfu + Ittsu
+        let tiles = parse_hand("23456789m123p11s").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        let (tile, win_type, score) = max_possible_score(&counts, &context).unwrap();
+
+        assert_eq!(tile, Tile::suited(Suit::Man, 1));
+        assert_eq!(win_type, WinType::Tsumo);
+        assert_eq!(score.han, 4);
+        assert_eq!(score.fu.total, 20);
+    }
+
+    #[test]
+    fn test_max_possible_score_with_called_meld() {
+        // Open tanyao with a called 234p chi, waiting on 1m-4m
+        let counts = to_counts(&parse_hand("23m567p678s55s").unwrap());
+        let melds = [Meld::shuntsu_open(Tile::suited(Suit::Pin, 2))];
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).open();
+
+        let (tile, _, score) = max_possible_score_with_melds(&counts, &melds, &context).unwrap();
+        assert_eq!(tile, Tile::suited(Suit::Man, 4));
+        assert_eq!(score.han, 1);
+
+        // The concealed tiles alone are not a 13-tile hand
+        assert!(max_possible_score(&counts, &context).is_none());
+    }
+
+    #[test]
+    fn test_max_possible_score_not_tenpai() {
+        let tiles = parse_hand("1357m2468p1357s1z").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        assert!(max_possible_score(&counts, &context).is_none());
+    }
+
     #[test]
     fn test_inference_preserves_dora_count() {
         // Verify that inference doesn't affect dora counting
@@ -2145,4 +3986,100 @@ mod tests {
             best_dora.payment.total
         );
     }
+
+    // ===== Corpus Tests =====
+
+    /// Score one `hand,winning_tile,flags` corpus row.
+    ///
+    /// `flags` is a space-separated list of `tsumo`, `riichi`, `ippatsu`,
+    /// `dealer`, `last_tile` and `dora=<indicators>`; an empty list means a
+    /// non-dealer ron. Called melds in the hand mark it open.
+    fn score_from_line(line: &str) -> Result<ScoringResult, String> {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [hand, winning_tile, flags] = fields[..] else {
+            return Err(format!("Expected 3 fields, got {}: {}", fields.len(), line));
+        };
+
+        let parsed = crate::parse::parse_hand_with_aka(hand)?;
+        let winning_tile = match parse_hand(winning_tile)?[..] {
+            [tile] => tile,
+            _ => return Err(format!("Invalid winning tile: {}", winning_tile)),
+        };
+
+        let mut context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(winning_tile)
+            .with_aka(parsed.aka_count);
+        for flag in flags.split_whitespace() {
+            context = match flag {
+                "tsumo" => GameContext {
+                    win_type: WinType::Tsumo,
+                    ..context
+                },
+                "riichi" => context.riichi(),
+                "ippatsu" => context.ippatsu(),
+                "last_tile" => context.last_tile(),
+                "dealer" => GameContext {
+                    seat_wind: Honor::East,
+                    ..context
+                },
+                _ => match flag.strip_prefix("dora=") {
+                    Some(indicators) => context.with_dora(parse_hand(indicators)?),
+                    None => return Err(format!("Unknown flag: {}", flag)),
+                },
+            };
+        }
+
+        let called_melds: Vec<Meld> = parsed
+            .called_melds
+            .iter()
+            .map(|cm| cm.meld.clone())
+            .collect();
+        if called_melds.iter().any(|m| m.is_open()) {
+            context = context.open();
+        }
+
+        let hand_counts = parsed.concealed_counts();
+        let all_counts = parsed.all_counts();
+
+        let structures = decompose_hand_with_melds(&hand_counts, &called_melds);
+        rank_interpretations(&structures, &all_counts, &context)
+            .into_iter()
+            .next()
+            .map(|best| best.score)
+            .ok_or_else(|| format!("No valid structure: {}", hand))
+    }
+
+    /// Hands with known Tenhou scores: (row, han, fu, total points)
+    const SCORE_CORPUS: &[(&str, u8, u8, u32)] = &[
+        // Riichi, pinfu, tanyao
+        ("234567m234p345s88s,2m,riichi", 3, 30, 3900),
+        ("234567m234p345s88s,2m,riichi dora=1m", 4, 30, 7700),
+        // Menzen tsumo, pinfu, tanyao: 700/1300
+        ("234567m234p345s88s,2m,tsumo", 3, 20, 2700),
+        // Double East, closed honor triplet and penchan
+        ("123m456p789s11122z,3m,dealer", 2, 40, 3900),
+        // Open honitsu, 500/1000
+        ("(111m)234567m789m11z,9m,tsumo", 2, 30, 2000),
+    ];
+
+    #[test]
+    fn test_score_corpus() {
+        for &(line, han, fu, points) in SCORE_CORPUS {
+            let result = score_from_line(line).unwrap();
+            assert_eq!(
+                (result.han, result.fu.total, result.payment.total),
+                (han, fu, points),
+                "mismatch for {}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn test_score_from_line_rejects_bad_rows() {
+        assert!(score_from_line("234567m234p345s88s,2m").is_err());
+        assert!(score_from_line("234567m234p345s88s,2m3m,riichi").is_err());
+        assert!(score_from_line("234567m234p345s88s,2m,bogus").is_err());
+        assert!(score_from_line("123m,2m,").is_err());
+    }
 }
@@ -19,6 +19,9 @@ pub struct ShantenResult {
     pub shanten: i8,
     /// The type of hand structure that gives the best shanten
     pub best_type: ShantenType,
+    /// Heuristic note on what is holding the hand back (None once tenpai)
+    #[serde(default)]
+    pub bottleneck: Option<String>,
 }
 
 /// Type of hand structure for shanten calculation
@@ -51,33 +54,148 @@ pub fn calculate_shanten_with_melds(counts: &TileCounts, called_melds: u8) -> Sh
     let standard = calculate_standard_shanten_with_melds(counts, called_melds);
 
     // Chiitoitsu and Kokushi are not possible with called melds
-    if called_melds > 0 {
-        return ShantenResult {
-            shanten: standard,
-            best_type: ShantenType::Standard,
-        };
-    }
+    let (shanten, best_type) = if called_melds > 0 {
+        (standard, ShantenType::Standard)
+    } else {
+        let chiitoi = calculate_chiitoitsu_shanten(counts);
+        let kokushi = calculate_kokushi_shanten(counts);
+
+        // Use the best (lowest) shanten
+        if standard <= chiitoi && standard <= kokushi {
+            (standard, ShantenType::Standard)
+        } else if chiitoi <= kokushi {
+            (chiitoi, ShantenType::Chiitoitsu)
+        } else {
+            (kokushi, ShantenType::Kokushi)
+        }
+    };
+
+    let bottleneck = if shanten > 0 {
+        Some(describe_bottleneck(counts, called_melds, best_type))
+    } else {
+        None
+    };
 
-    let chiitoi = calculate_chiitoitsu_shanten(counts);
-    let kokushi = calculate_kokushi_shanten(counts);
+    ShantenResult {
+        shanten,
+        best_type,
+        bottleneck,
+    }
+}
 
-    // Return the best (lowest) shanten
-    if standard <= chiitoi && standard <= kokushi {
-        ShantenResult {
-            shanten: standard,
-            best_type: ShantenType::Standard,
+/// Describe what is keeping a hand from progressing, for learners
+///
+/// Heuristic only: for standard hands it reports missing partial sets (naming an
+/// isolated terminal or honor if there is one), a missing pair, or incomplete sets.
+fn describe_bottleneck(counts: &TileCounts, called_melds: u8, best_type: ShantenType) -> String {
+    match best_type {
+        ShantenType::Chiitoitsu => {
+            let pairs = counts.values().filter(|&&c| c >= 2).count();
+            format!("need {} more pairs", 7usize.saturating_sub(pairs))
         }
-    } else if chiitoi <= kokushi {
-        ShantenResult {
-            shanten: chiitoi,
-            best_type: ShantenType::Chiitoitsu,
+        ShantenType::Kokushi => {
+            let types = KOKUSHI_TILES
+                .iter()
+                .filter(|tile| counts.get(tile).copied().unwrap_or(0) > 0)
+                .count();
+            format!("need {} more terminal/honor types", 13 - types)
         }
-    } else {
-        ShantenResult {
-            shanten: kokushi,
-            best_type: ShantenType::Kokushi,
+        ShantenType::Standard => standard_bottleneck(counts, called_melds),
+    }
+}
+
+fn standard_bottleneck(counts: &TileCounts, called_melds: u8) -> String {
+    let tiles = counts_to_array(counts);
+    let needed = 4u8.saturating_sub(called_melds);
+
+    // Block counts of the best pair choice, mirroring the shanten search
+    let mut best = (i8::MAX, 0u8, 0u8, false);
+    let mut consider = |arr: &[u8; 34], has_pair: bool| {
+        let (melds, taatsu) = count_melds_and_taatsu(arr);
+        let shanten = calculate_shanten_value_with_called(melds, taatsu, has_pair, called_melds, 0);
+        if shanten < best.0 {
+            best = (shanten, melds, taatsu, has_pair);
         }
+    };
+    consider(&tiles, false);
+    for i in 0..34 {
+        if tiles[i] >= 2 {
+            let mut without_pair = tiles;
+            without_pair[i] -= 2;
+            consider(&without_pair, true);
+        }
+    }
+    let (_, melds, taatsu, has_pair) = best;
+
+    if melds + taatsu < needed {
+        let missing = needed - melds - taatsu;
+        let need = if missing == 1 {
+            "need one more partial set".to_string()
+        } else {
+            format!("need {} more partial sets", missing)
+        };
+        return match isolated_yaochu(&tiles) {
+            Some(tile) if tile.is_honor() => format!("{}; isolated honor {}", need, tile),
+            Some(tile) => format!("{}; isolated terminal {}", need, tile),
+            None => need,
+        };
     }
+    if !has_pair {
+        return "no pair yet".to_string();
+    }
+    format!("need {} more complete sets", needed.saturating_sub(melds))
+}
+
+/// First terminal or honor with no copies or suit neighbours within two
+fn isolated_yaochu(tiles: &[u8; 34]) -> Option<Tile> {
+    (0..34)
+        .filter(|&i| tiles[i] == 1)
+        .map(|i| (i, index_to_tile(i)))
+        .find(|&(i, tile)| {
+            if tile.is_honor() {
+                return true;
+            }
+            if !tile.is_terminal() {
+                return false;
+            }
+            let suit_start = i / 9 * 9;
+            (suit_start..suit_start + 9)
+                .filter(|&j| j != i && j.abs_diff(i) <= 2)
+                .all(|j| tiles[j] == 0)
+        })
+        .map(|(_, tile)| tile)
+}
+
+/// Check if a closed hand (13 tiles) is tenpai
+pub fn is_tenpai(counts: &TileCounts) -> bool {
+    calculate_shanten(counts).shanten == 0
+}
+
+/// Check if riichi can be declared: the hand must be closed and tenpai
+pub fn can_declare_riichi(counts: &TileCounts, has_open_melds: bool) -> bool {
+    !has_open_melds && is_tenpai(counts)
+}
+
+/// Discards from a 14-tile hand that leave it tenpai
+///
+/// Returns each distinct tile in the hand whose removal leaves shanten 0,
+/// in sorted order.
+pub fn tenpai_preserving_discards(counts: &TileCounts) -> Vec<Tile> {
+    let mut discards: Vec<Tile> = counts
+        .iter()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(&tile, _)| tile)
+        .filter(|tile| {
+            let mut remaining = counts.clone();
+            *remaining.get_mut(tile).unwrap() -= 1;
+            if remaining[tile] == 0 {
+                remaining.remove(tile);
+            }
+            is_tenpai(&remaining)
+        })
+        .collect();
+    discards.sort();
+    discards
 }
 
 /// Calculate shanten for standard hand (4 melds + 1 pair)
@@ -541,6 +659,49 @@ fn calculate_ukeire_inner(
     }
 }
 
+/// Shanten after drawing each tile type (and making the best discard)
+///
+/// One entry per tile type in index order (1m..9m, 1p..9p, 1s..9s, 1z..7z), for a
+/// closed 13-tile hand. Tiles already held 4 times can't be drawn and are skipped.
+/// Unlike [`calculate_ukeire`], draws that don't help are included too.
+pub fn draw_outcomes(counts: &TileCounts) -> Vec<(Tile, i8)> {
+    (0..34)
+        .map(index_to_tile)
+        .filter(|tile| counts.get(tile).copied().unwrap_or(0) < 4)
+        .map(|tile| {
+            let mut drawn = counts.clone();
+            *drawn.entry(tile).or_insert(0) += 1;
+            (tile, calculate_shanten(&drawn).shanten)
+        })
+        .collect()
+}
+
+/// Every discard from a 14-tile hand with the ukeire it leaves, best first
+///
+/// One entry per distinct tile in a closed hand, sorted by lowest shanten, then
+/// highest `total_count`, then tile order.
+pub fn best_discards(counts: &TileCounts) -> Vec<(Tile, UkeireResult)> {
+    let mut discards: Vec<(Tile, UkeireResult)> = counts
+        .iter()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(&tile, _)| {
+            let mut remaining = counts.clone();
+            *remaining.get_mut(&tile).unwrap() -= 1;
+            if remaining[&tile] == 0 {
+                remaining.remove(&tile);
+            }
+            (tile, calculate_ukeire(&remaining))
+        })
+        .collect();
+    discards.sort_by(|(a_tile, a), (b_tile, b)| {
+        a.shanten
+            .cmp(&b.shanten)
+            .then_with(|| b.total_count.cmp(&a.total_count))
+            .then_with(|| a_tile.cmp(b_tile))
+    });
+    discards
+}
+
 /// Result of ukeire calculation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UkeireResult {
@@ -637,6 +798,85 @@ mod tests {
         assert_eq!(shanten("1122m3344p5566s"), 1);
     }
 
+    // ===== Riichi Declaration Tests =====
+
+    #[test]
+    fn test_is_tenpai() {
+        let counts = to_counts(&parse_hand("123m456p789s1112z").unwrap());
+        assert!(is_tenpai(&counts));
+
+        let counts = to_counts(&parse_hand("123m456p789s11122z").unwrap());
+        assert!(!is_tenpai(&counts));
+    }
+
+    #[test]
+    fn test_can_declare_riichi_closed_tenpai() {
+        let counts = to_counts(&parse_hand("123m456p789s1112z").unwrap());
+        assert!(can_declare_riichi(&counts, false));
+    }
+
+    #[test]
+    fn test_can_declare_riichi_open_tenpai() {
+        // Same tenpai shape, but with a called meld
+        let counts = to_counts(&parse_hand("123m456p789s1112z").unwrap());
+        assert!(!can_declare_riichi(&counts, true));
+    }
+
+    #[test]
+    fn test_can_declare_riichi_iishanten() {
+        let counts = to_counts(&parse_hand("123m456p789s1134z").unwrap());
+        assert_eq!(calculate_shanten(&counts).shanten, 1);
+        assert!(!can_declare_riichi(&counts, false));
+    }
+
+    #[test]
+    fn test_tenpai_preserving_discards() {
+        // Cutting 3s leaves 57s, cutting 7s leaves 35s; anything else breaks tenpai
+        let counts = to_counts(&parse_hand("123m456p789s11z357s").unwrap());
+        assert_eq!(
+            tenpai_preserving_discards(&counts),
+            vec![Tile::suited(Suit::Sou, 3), Tile::suited(Suit::Sou, 7)]
+        );
+    }
+
+    // ===== Bottleneck Tests =====
+
+    #[test]
+    fn test_bottleneck_isolated_terminal() {
+        // Three sets and four isolated tiles: 2-shanten, one partial set short
+        let counts = to_counts(&parse_hand("123m456p789s9m137z").unwrap());
+        let result = calculate_shanten(&counts);
+        assert_eq!(result.shanten, 2);
+        assert_eq!(
+            result.bottleneck.as_deref(),
+            Some("need one more partial set; isolated terminal 9m")
+        );
+    }
+
+    #[test]
+    fn test_bottleneck_none_when_tenpai() {
+        let counts = to_counts(&parse_hand("123m456p789s1122z").unwrap());
+        let result = calculate_shanten(&counts);
+        assert_eq!(result.shanten, 0);
+        assert_eq!(result.bottleneck, None);
+    }
+
+    // ===== Red Five Tests =====
+
+    #[test]
+    fn test_red_fives_do_not_change_shanten() {
+        assert_eq!(shanten("340m406p789s1122z"), shanten("345m456p789s1122z"));
+        assert_eq!(shanten("0m0p0s123456m11z"), shanten("5m5p5s123456m11z"));
+    }
+
+    #[test]
+    fn test_red_fives_do_not_change_ukeire() {
+        let red = calculate_ukeire(&to_counts(&parse_hand("340m406p789s1122z").unwrap()));
+        let plain = calculate_ukeire(&to_counts(&parse_hand("345m456p789s1122z").unwrap()));
+        assert_eq!(red.shanten, plain.shanten);
+        assert_eq!(red.total_count, plain.total_count);
+    }
+
     // ===== Various Shanten Tests =====
 
     #[test]
@@ -722,6 +962,37 @@ mod tests {
         assert!(ukeire.tiles.is_empty());
     }
 
+    #[test]
+    fn test_draw_outcomes() {
+        // Tenpai on 2z tanki: drawing it completes the hand, 9p changes nothing
+        let tiles = parse_hand("123m456p789s1112z").unwrap();
+        let counts = to_counts(&tiles);
+        let outcomes = draw_outcomes(&counts);
+
+        let shanten_after = |tile: Tile| outcomes.iter().find(|(t, _)| *t == tile).unwrap().1;
+        assert_eq!(shanten_after(Tile::honor(Honor::South)), -1);
+        assert_eq!(shanten_after(Tile::suited(Suit::Pin, 9)), 0);
+        assert_eq!(outcomes.len(), 34);
+    }
+
+    #[test]
+    fn test_best_discards() {
+        // Cutting 9m leaves a 1m/4m ryanmen: 3 + 4 tiles
+        let tiles = parse_hand("123m456p789s239m55z").unwrap();
+        let counts = to_counts(&tiles);
+        let discards = best_discards(&counts);
+
+        assert_eq!(discards.len(), 11);
+        let (tile, ukeire) = &discards[0];
+        assert_eq!(*tile, Tile::suited(Suit::Man, 9));
+        assert_eq!(ukeire.shanten, 0);
+        assert_eq!(ukeire.total_count, 7);
+        assert!(discards.windows(2).all(|w| {
+            (w[0].1.shanten, std::cmp::Reverse(w[0].1.total_count))
+                <= (w[1].1.shanten, std::cmp::Reverse(w[1].1.total_count))
+        }));
+    }
+
     // ===== Ukeire with Called Melds Tests =====
 
     #[test]
@@ -0,0 +1,118 @@
+//! Aggregate statistics over many scored hands
+//!
+//! Feed each scored hand to a [`YakuStats`] accumulator to tally how often each
+//! yaku appears and the average han and fu across the set.
+
+use std::collections::HashMap;
+
+use crate::scoring::ScoringResult;
+use crate::yaku::{Yaku, YakuResult};
+
+/// Running yaku frequencies and han/fu totals over a set of hands
+#[derive(Debug, Clone, Default)]
+pub struct YakuStats {
+    /// Number of hands recorded
+    pub hands: usize,
+    /// How many recorded hands contained each yaku
+    pub yaku_counts: HashMap<Yaku, usize>,
+    total_han: u32,
+    total_fu: u32,
+}
+
+impl YakuStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one scored hand to the totals
+    pub fn record(&mut self, yaku_result: &YakuResult, score: &ScoringResult) {
+        self.hands += 1;
+        for &yaku in &yaku_result.yaku_list {
+            *self.yaku_counts.entry(yaku).or_insert(0) += 1;
+        }
+        self.total_han += score.han as u32;
+        self.total_fu += score.fu.total as u32;
+    }
+
+    /// Number of recorded hands containing `yaku`
+    pub fn count(&self, yaku: Yaku) -> usize {
+        self.yaku_counts.get(&yaku).copied().unwrap_or(0)
+    }
+
+    /// Average han per hand (0.0 when nothing was recorded)
+    pub fn average_han(&self) -> f64 {
+        self.average(self.total_han)
+    }
+
+    /// Average fu per hand (0.0 when nothing was recorded)
+    pub fn average_fu(&self) -> f64 {
+        self.average(self.total_fu)
+    }
+
+    /// Yaku sorted by frequency (most common first, ties by name)
+    pub fn sorted_counts(&self) -> Vec<(Yaku, usize)> {
+        let mut counts: Vec<_> = self.yaku_counts.iter().map(|(&y, &c)| (y, c)).collect();
+        counts.sort_by(|(a, a_count), (b, b_count)| {
+            b_count
+                .cmp(a_count)
+                .then_with(|| a.to_string().cmp(&b.to_string()))
+        });
+        counts
+    }
+
+    fn average(&self, total: u32) -> f64 {
+        if self.hands == 0 {
+            0.0
+        } else {
+            total as f64 / self.hands as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{GameContext, WinType};
+    use crate::hand::decompose_hand;
+    use crate::parse::{parse_hand, to_counts};
+    use crate::scoring::calculate_score;
+    use crate::tile::{Honor, Suit, Tile};
+    use crate::yaku::detect_yaku_with_context;
+
+    fn record_hand(stats: &mut YakuStats, hand: &str, context: &GameContext) {
+        let counts = to_counts(&parse_hand(hand).unwrap());
+        let structure = &decompose_hand(&counts)[0];
+        let yaku_result = detect_yaku_with_context(structure, &counts, context);
+        let score = calculate_score(structure, &yaku_result, context);
+        stats.record(&yaku_result, &score);
+    }
+
+    #[test]
+    fn test_empty_stats() {
+        let stats = YakuStats::new();
+        assert_eq!(stats.hands, 0);
+        assert_eq!(stats.average_han(), 0.0);
+        assert!(stats.sorted_counts().is_empty());
+    }
+
+    #[test]
+    fn test_record_tallies_yaku_and_averages() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 2))
+            .riichi();
+        let mut stats = YakuStats::new();
+
+        // Riichi + pinfu + tanyao, 3 han 30 fu
+        record_hand(&mut stats, "234567m234p345s88s", &context);
+        // Riichi + haku on a 2m tanki, 2 han 40 fu
+        record_hand(&mut stats, "123m456p789s555z22m", &context);
+
+        assert_eq!(stats.hands, 2);
+        assert_eq!(stats.count(Yaku::Riichi), 2);
+        assert_eq!(stats.count(Yaku::Tanyao), 1);
+        assert_eq!(stats.count(Yaku::Chinitsu), 0);
+        assert_eq!(stats.sorted_counts()[0], (Yaku::Riichi, 2));
+        assert_eq!(stats.average_han(), 2.5);
+        assert_eq!(stats.average_fu(), 35.0);
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Suit {
@@ -145,51 +146,92 @@ pub const KOKUSHI_TILES: [Tile; 13] = [
     Tile::Honor(Honor::Red),
 ];
 
-impl TryFrom<&str> for Tile {
-    type Error = String;
-
-    fn try_from(input: &str) -> Result<Self, Self::Error> {
-        // Convert the string to a list of characters
-        let chars: Vec<char> = input.chars().collect();
+/// The three suits, in man, pin, sou order
+pub fn all_suits() -> [Suit; 3] {
+    [Suit::Man, Suit::Pin, Suit::Sou]
+}
 
-        // Basic validation: "1m" or "ew" are 2 characters
-        if chars.len() != 2 {
-            return Err(format!("Invalid tile format: {}", input));
+/// All 34 distinct tiles, in man, pin, sou, honor order
+pub fn all_tiles() -> Vec<Tile> {
+    let mut tiles = Vec::with_capacity(34);
+    for suit in all_suits() {
+        for value in 1..=9 {
+            tiles.push(Tile::suited(suit, value));
         }
+    }
+    for honor in [
+        Honor::East,
+        Honor::South,
+        Honor::West,
+        Honor::North,
+        Honor::White,
+        Honor::Green,
+        Honor::Red,
+    ] {
+        tiles.push(Tile::honor(honor));
+    }
+    tiles
+}
 
-        let value_char = chars[0];
-        let type_char = chars[1];
-
-        // TASK: Use a 'match' statement here to handle type_char
-        // 'm' -> Suit::Man
-        // 'p' -> Suit::Pin
-        // 's' -> Suit::Sou
-        // 'z' -> This is usually how honors are represented (1z = East, etc.)
-
-        match type_char {
-            'm' | 'p' | 's' => {
-                let suit = match type_char {
-                    'm' => Suit::Man,
-                    'p' => Suit::Pin,
-                    _ => Suit::Sou,
-                };
+/// Error produced when hand or tile notation cannot be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A single tile string is not valid notation (e.g., "8z")
+    InvalidTile(String),
+    /// The hand notation is malformed
+    InvalidHand(String),
+}
 
-                // Convert value_char to a digit (1-9)
-                let val = value_char.to_digit(10).ok_or("Not a digit")? as u8;
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidTile(tile) => write!(f, "Invalid tile: {}", tile),
+            ParseError::InvalidHand(msg) => write!(f, "{}", msg),
+        }
+    }
+}
 
-                if !(1..=9).contains(&val) {
-                    return Err("Suited tiles must be 1-9".to_string());
-                }
+impl std::error::Error for ParseError {}
+
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    /// Parse a single tile in any supported notation (case-insensitive):
+    /// numeric (`5m`, `1z`), red five (`0p`), honor letters (`e`, `wh`) or
+    /// names (`east`, `haku`)
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidTile(input.to_string());
+        let s = input.trim().to_lowercase();
+
+        let honor = match s.as_str() {
+            "e" | "east" => Some(Honor::East),
+            "s" | "south" => Some(Honor::South),
+            "w" | "west" => Some(Honor::West),
+            "n" | "north" => Some(Honor::North),
+            "wh" | "white" | "haku" => Some(Honor::White),
+            "g" | "green" | "hatsu" => Some(Honor::Green),
+            "r" | "red" | "chun" => Some(Honor::Red),
+            _ => None,
+        };
+        if let Some(honor) = honor {
+            return Ok(Tile::Honor(honor));
+        }
 
-                Ok(Tile::Suited { suit, value: val })
-            }
+        // Numeric notation: exactly one digit followed by the suit
+        let chars: Vec<char> = s.chars().collect();
+        let [value_char, suit_char] = chars[..] else {
+            return Err(invalid());
+        };
+        let digit = value_char.to_digit(10).ok_or_else(invalid)? as u8;
+        // Red fives are written as 0 in the suits
+        let value = if digit == 0 { 5 } else { digit };
+
+        match suit_char {
+            'm' => Ok(Tile::suited(Suit::Man, value)),
+            'p' => Ok(Tile::suited(Suit::Pin, value)),
+            's' => Ok(Tile::suited(Suit::Sou, value)),
             'z' => {
-                // Map 1-7 to the Honor enum variants
-                // 1=East, 2=South, 3=West, 4=North, 5=White, 6=Green, 7=Red
-                // 1. Create a variable for the specific Honor variant
-                let val_digit = value_char.to_digit(10).ok_or("Not a digit")? as u8;
-                // 2. Use a match on val_digit (1 through 7)
-                let honor_variant = match val_digit {
+                let honor = match digit {
                     1 => Honor::East,
                     2 => Honor::South,
                     3 => Honor::West,
@@ -197,18 +239,23 @@ impl TryFrom<&str> for Tile {
                     5 => Honor::White,
                     6 => Honor::Green,
                     7 => Honor::Red,
-                    // ... and so on ...
-                    _ => return Err("Out of bounds for Honors".into()),
+                    _ => return Err(invalid()),
                 };
-
-                // 3. Wrap that variant into the Tile enum and return it successfully
-                Ok(Tile::Honor(honor_variant))
+                Ok(Tile::Honor(honor))
             }
-            _ => Err(format!("Unknown suit: {}", type_char)),
+            _ => Err(invalid()),
         }
     }
 }
 
+impl TryFrom<&str> for Tile {
+    type Error = String;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse().map_err(|e: ParseError| e.to_string())
+    }
+}
+
 // The "Pretty Printer"
 // This lets you use println!("{}", tile) instead of println!("{:?}", tile)
 impl fmt::Display for Tile {
@@ -254,6 +301,32 @@ mod tests {
         assert_eq!(five_man, Tile::suited(Suit::Man, 5));
     }
 
+    #[test]
+    fn parse_tile_notation_variants() {
+        let parse = |s: &str| s.parse::<Tile>().unwrap();
+
+        assert_eq!(parse("5m"), Tile::suited(Suit::Man, 5));
+        assert_eq!(parse("9S"), Tile::suited(Suit::Sou, 9));
+        assert_eq!(parse("0p"), Tile::suited(Suit::Pin, 5));
+        assert_eq!(parse("7z"), Tile::honor(Honor::Red));
+        assert_eq!(parse("e"), Tile::honor(Honor::East));
+        assert_eq!(parse("Wh"), Tile::honor(Honor::White));
+        assert_eq!(parse("north"), Tile::honor(Honor::North));
+        assert_eq!(parse("hatsu"), Tile::honor(Honor::Green));
+        assert_eq!(parse(" 3z "), Tile::honor(Honor::West));
+    }
+
+    #[test]
+    fn parse_tile_rejects_invalid_input() {
+        for input in ["8z", "0z", "5x", "m5", "12m", "", "dragon"] {
+            assert_eq!(
+                input.parse::<Tile>(),
+                Err(ParseError::InvalidTile(input.to_string())),
+                "{input:?}"
+            );
+        }
+    }
+
     #[test]
     fn tile_properties() {
         // Simples: 2-8 of any suit
@@ -268,4 +341,19 @@ mod tests {
         assert!(Tile::honor(Honor::White).is_terminal_or_honor());
         assert!(!Tile::suited(Suit::Man, 5).is_terminal_or_honor());
     }
+
+    #[test]
+    fn all_suits_order() {
+        assert_eq!(all_suits(), [Suit::Man, Suit::Pin, Suit::Sou]);
+    }
+
+    #[test]
+    fn all_tiles_covers_every_tile_once() {
+        let tiles = all_tiles();
+        assert_eq!(tiles.len(), 34);
+        let unique: std::collections::HashSet<_> = tiles.iter().collect();
+        assert_eq!(unique.len(), 34);
+        assert_eq!(tiles[0], Tile::suited(Suit::Man, 1));
+        assert_eq!(tiles[33], Tile::honor(Honor::Red));
+    }
 }
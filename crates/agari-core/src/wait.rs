@@ -7,7 +7,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::context::GameContext;
 use crate::hand::{HandStructure, Meld};
-use crate::tile::{Honor, Tile};
+use crate::parse::TileCounts;
+use crate::shanten::calculate_shanten_with_melds;
+use crate::tile::{Honor, Tile, all_tiles};
 
 /// The type of wait that led to the winning hand
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -50,6 +52,18 @@ impl WaitType {
         }
     }
 
+    /// Lowercase name for machine-readable output (e.g., "kanchan")
+    pub fn name(&self) -> &'static str {
+        match self {
+            WaitType::Ryanmen => "ryanmen",
+            WaitType::Kanchan => "kanchan",
+            WaitType::Penchan => "penchan",
+            WaitType::Shanpon => "shanpon",
+            WaitType::Tanki => "tanki",
+            WaitType::Kokushi13 => "kokushi13",
+        }
+    }
+
     /// Is this a "good" wait (multiple outs)?
     pub fn is_good_wait(&self) -> bool {
         matches!(
@@ -226,6 +240,11 @@ pub fn is_pinfu(structure: &HandStructure, winning_tile: Tile, context: &GameCon
         HandStructure::Kokushi { .. } => false, // Kokushi can never be pinfu
 
         HandStructure::Standard { melds, pair } => {
+            // A called meld means the hand is open, even if the context wasn't marked open
+            if melds.iter().any(|m| m.is_open()) {
+                return false;
+            }
+
             // 1. All melds must be sequences (no triplets or kans)
             let all_sequences = melds.iter().all(|m| m.is_sequence());
             if !all_sequences {
@@ -237,9 +256,11 @@ pub fn is_pinfu(structure: &HandStructure, winning_tile: Tile, context: &GameCon
                 return false;
             }
 
-            // 3. Must have ryanmen wait
+            // 3. Must have ryanmen wait, and (unless pinfu is preferred) no
+            //    higher-fu reading of the same wait
             let wait_types = detect_wait_types(structure, winning_tile);
             wait_types.contains(&WaitType::Ryanmen)
+                && (context.rules.prefer_pinfu || wait_types.iter().all(|wt| wt.fu() == 0))
         }
     }
 }
@@ -310,6 +331,45 @@ pub fn best_wait_type_for_scoring(
     })
 }
 
+/// The wait fu is scored from, given the context's winning tile
+///
+/// Ryanmen when the hand is pinfu, otherwise the highest-fu reading as in
+/// [`best_wait_type_for_scoring`]. `None` without a winning tile.
+pub fn wait_type(structure: &HandStructure, context: &GameContext) -> Option<WaitType> {
+    let winning_tile = context.winning_tile?;
+    if is_pinfu(structure, winning_tile, context) {
+        Some(WaitType::Ryanmen)
+    } else {
+        best_wait_type_for_scoring(structure, winning_tile)
+    }
+}
+
+/// Find every tile that completes a tenpai hand, accounting for called melds.
+///
+/// `counts` holds the concealed tiles only (13, minus 3 per called meld); called
+/// melds are inferred from the tile count. Tiles already held four times are
+/// skipped. Returns tiles in sorted order, empty when the hand isn't tenpai or has
+/// an impossible tile count.
+pub fn calculate_waits(counts: &TileCounts) -> Vec<Tile> {
+    let tile_count: u32 = counts.values().map(|&c| c as u32).sum();
+    if tile_count > 13 || !(13 - tile_count).is_multiple_of(3) {
+        return Vec::new();
+    }
+    let called_melds = ((13 - tile_count) / 3) as u8;
+
+    all_tiles()
+        .into_iter()
+        .filter(|tile| {
+            if counts.get(tile).copied().unwrap_or(0) >= 4 {
+                return false;
+            }
+            let mut with_tile = counts.clone();
+            *with_tile.entry(*tile).or_insert(0) += 1;
+            calculate_shanten_with_melds(&with_tile, called_melds).shanten == -1
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -557,6 +617,23 @@ mod tests {
         assert!(has_pinfu, "Non-value wind pair allows pinfu");
     }
 
+    #[test]
+    fn test_pinfu_ok_with_terminal_pair() {
+        // Same shape as the haku-pair hand above, with a 99m pair instead
+        let tiles = parse_hand("123m456m789p234s99m").unwrap();
+        let counts = to_counts(&tiles);
+        let structures = decompose_hand(&counts);
+
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South);
+        let winning_tile = Tile::suited(Suit::Sou, 4); // Ryanmen
+
+        let has_pinfu = structures
+            .iter()
+            .any(|s| is_pinfu(s, winning_tile, &context));
+
+        assert!(has_pinfu, "Terminal pair is not yakuhai and allows pinfu");
+    }
+
     #[test]
     fn test_pinfu_fails_with_kanchan_wait() {
         // All sequences, good pair, but kanchan wait
@@ -590,6 +667,23 @@ mod tests {
         assert!(!has_pinfu, "Open hand can't be pinfu");
     }
 
+    #[test]
+    fn test_pinfu_fails_with_called_chi_even_if_context_closed() {
+        // Called chi (234s) but context not marked open: still never pinfu
+        let structure = HandStructure::Standard {
+            melds: vec![
+                Meld::shuntsu(Tile::suited(Suit::Man, 1)),
+                Meld::shuntsu(Tile::suited(Suit::Man, 4)),
+                Meld::shuntsu(Tile::suited(Suit::Pin, 7)),
+                Meld::shuntsu_open(Tile::suited(Suit::Sou, 2)),
+            ],
+            pair: Tile::suited(Suit::Pin, 5),
+        };
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+
+        assert!(!is_pinfu(&structure, Tile::suited(Suit::Man, 1), &context));
+    }
+
     // ===== Best Wait Type Tests =====
 
     #[test]
@@ -612,4 +706,75 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_wait_type_follows_scoring() {
+        let tiles = parse_hand("234m456p789s567m55s").unwrap();
+        let counts = to_counts(&tiles);
+        let structures = decompose_hand(&counts);
+        assert_eq!(structures.len(), 1);
+
+        // Pinfu: scored as ryanmen
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Man, 4));
+        assert_eq!(wait_type(&structures[0], &context), Some(WaitType::Ryanmen));
+
+        // 3m only fits the middle of 234m
+        let context = context.with_winning_tile(Tile::suited(Suit::Man, 3));
+        assert_eq!(wait_type(&structures[0], &context), Some(WaitType::Kanchan));
+
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        assert_eq!(wait_type(&structures[0], &context), None);
+    }
+
+    // ===== Waits Tests =====
+
+    #[test]
+    fn test_calculate_waits_ryanmen() {
+        let tiles = parse_hand("123m456p789s23m55z").unwrap();
+        let counts = to_counts(&tiles);
+
+        assert_eq!(
+            calculate_waits(&counts),
+            vec![Tile::suited(Suit::Man, 1), Tile::suited(Suit::Man, 4)]
+        );
+    }
+
+    #[test]
+    fn test_calculate_waits_skips_fourth_copy() {
+        // 1111m held: a tanki wait on 1m would need a fifth copy
+        let tiles = parse_hand("1111m456p789s555z").unwrap();
+        let counts = to_counts(&tiles);
+
+        assert!(!calculate_waits(&counts).contains(&Tile::suited(Suit::Man, 1)));
+    }
+
+    #[test]
+    fn test_calculate_waits_not_tenpai() {
+        let tiles = parse_hand("1357m2468p1357s1z").unwrap();
+        let counts = to_counts(&tiles);
+
+        assert!(calculate_waits(&counts).is_empty());
+    }
+
+    #[test]
+    fn test_calculate_waits() {
+        // Chiitoitsu tanki and kokushi 13-sided waits go through shanten too
+        let counts = to_counts(&parse_hand("1122m3344p5566s7z").unwrap());
+        assert_eq!(calculate_waits(&counts), vec![Tile::honor(Honor::Red)]);
+
+        let counts = to_counts(&parse_hand("19m19p19s1234567z").unwrap());
+        assert_eq!(calculate_waits(&counts).len(), 13);
+
+        // (567s) called: 10 concealed tiles waiting on 1m/4m
+        let counts = to_counts(&parse_hand("23m456p11122z").unwrap());
+        assert_eq!(
+            calculate_waits(&counts),
+            vec![Tile::suited(Suit::Man, 1), Tile::suited(Suit::Man, 4)]
+        );
+
+        // Impossible tile counts have no waits
+        let counts = to_counts(&parse_hand("23m456p1112z").unwrap());
+        assert!(calculate_waits(&counts).is_empty());
+    }
 }
@@ -2,12 +2,16 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::context::{GameContext, WinType, count_dora_detailed};
-use crate::hand::{HandStructure, Meld};
+use crate::context::{GameContext, WinType, count_dora_detailed, count_kan_dora};
+use crate::hand::{
+    HandStructure, KanType, Meld, collect_all_tiles, collect_meld_tiles, decompose_hand,
+    decompose_hand_with_melds,
+};
 use crate::parse::TileCounts;
 use crate::tile::{Honor, Suit, Tile};
-use crate::wait::is_pinfu;
+use crate::wait::{calculate_waits, is_pinfu};
 use std::collections::HashMap;
+use std::fmt;
 
 /// Represents a scoring pattern (yaku)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -185,6 +189,15 @@ impl Yaku {
         self.han_open().is_some()
     }
 
+    /// Han value for an open or closed hand (`None` if invalid when open)
+    pub fn effective_han(&self, is_open: bool) -> Option<u8> {
+        if is_open {
+            self.han_open()
+        } else {
+            Some(self.han())
+        }
+    }
+
     /// Check if this is a yakuman (limit hand)
     pub fn is_yakuman(&self) -> bool {
         matches!(
@@ -208,6 +221,68 @@ impl Yaku {
     }
 }
 
+/// Canonical yaku name, shared by the CLI and the wasm bindings (the web UI
+/// keys its translations off these strings)
+impl fmt::Display for Yaku {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Yaku::Riichi => "Riichi",
+            Yaku::Ippatsu => "Ippatsu",
+            Yaku::MenzenTsumo => "Menzen Tsumo",
+            Yaku::Tanyao => "Tanyao",
+            Yaku::Pinfu => "Pinfu",
+            Yaku::Iipeikou => "Iipeikou",
+            Yaku::Yakuhai(honor) => {
+                let honor_name = match honor {
+                    Honor::East => "East",
+                    Honor::South => "South",
+                    Honor::West => "West",
+                    Honor::North => "North",
+                    Honor::White => "Haku",
+                    Honor::Green => "Hatsu",
+                    Honor::Red => "Chun",
+                };
+                return write!(f, "Yakuhai ({})", honor_name);
+            }
+            Yaku::RinshanKaihou => "Rinshan Kaihou",
+            Yaku::Chankan => "Chankan",
+            Yaku::HaiteiRaoyue => "Haitei Raoyue",
+            Yaku::HouteiRaoyui => "Houtei Raoyui",
+            Yaku::DoubleRiichi => "Double Riichi",
+            Yaku::Toitoi => "Toitoi",
+            Yaku::SanshokuDoujun => "Sanshoku Doujun",
+            Yaku::SanshokuDoukou => "Sanshoku Doukou",
+            Yaku::Ittsu => "Ittsu",
+            Yaku::Chiitoitsu => "Chiitoitsu",
+            Yaku::Chanta => "Chanta",
+            Yaku::SanAnkou => "San Ankou",
+            Yaku::SanKantsu => "San Kantsu",
+            Yaku::Honroutou => "Honroutou",
+            Yaku::Shousangen => "Shousangen",
+            Yaku::Honitsu => "Honitsu",
+            Yaku::Junchan => "Junchan",
+            Yaku::Ryanpeikou => "Ryanpeikou",
+            Yaku::Chinitsu => "Chinitsu",
+            Yaku::Tenhou => "Tenhou",
+            Yaku::Chiihou => "Chiihou",
+            Yaku::KokushiMusou => "Kokushi Musou",
+            Yaku::Suuankou => "Suuankou",
+            Yaku::Daisangen => "Daisangen",
+            Yaku::Shousuushii => "Shousuushii",
+            Yaku::Daisuushii => "Daisuushii",
+            Yaku::Tsuuiisou => "Tsuuiisou",
+            Yaku::Chinroutou => "Chinroutou",
+            Yaku::Ryuuiisou => "Ryuuiisou",
+            Yaku::ChuurenPoutou => "Chuuren Poutou",
+            Yaku::SuuKantsu => "Suu Kantsu",
+            Yaku::Kokushi13Wait => "Kokushi 13-Wait",
+            Yaku::SuuankouTanki => "Suuankou Tanki",
+            Yaku::JunseiChuurenPoutou => "Junsei Chuuren Poutou",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// Result of yaku detection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YakuResult {
@@ -219,6 +294,9 @@ pub struct YakuResult {
     pub ura_dora: u8,
     pub aka_dora: u8,
     pub is_yakuman: bool,
+    /// Closed-only yaku the hand would have had if it were closed (open hands only)
+    #[serde(default)]
+    pub dropped_open: Vec<Yaku>,
 }
 
 impl YakuResult {
@@ -233,6 +311,57 @@ impl YakuResult {
     }
 }
 
+/// Total han of a yaku list, applying the open-hand reductions when `is_open`.
+///
+/// Yaku that are invalid when open contribute 0 han.
+pub fn total_han(yaku_list: &[Yaku], is_open: bool) -> u8 {
+    yaku_list
+        .iter()
+        .filter_map(|y| y.effective_han(is_open))
+        .sum()
+}
+
+/// Han a single yaku is worth in an open or closed hand, for reference tables.
+///
+/// Free-function form of [`Yaku::effective_han`]: `None` when the yaku is invalid open.
+pub fn effective_han(y: Yaku, is_open: bool) -> Option<u8> {
+    y.effective_han(is_open)
+}
+
+/// Win conditions a yaku depends on, beyond the shape of the hand
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YakuRequirements {
+    /// Only awarded to a closed hand
+    pub closed_only: bool,
+    /// Only awarded on a self-draw
+    pub tsumo_only: bool,
+    /// Only awarded on a discard
+    pub ron_only: bool,
+    /// Only awarded to the dealer
+    pub dealer_only: bool,
+}
+
+/// Win conditions required for `y` to be awarded, for explaining why it didn't apply
+///
+/// Mirrors the checks in [`detect_yaku_with_context`]. Suuankou is not tsumo-only:
+/// a tanki ron completes the pair and leaves all four triplets concealed.
+pub fn yaku_requirements(y: Yaku) -> YakuRequirements {
+    let closed_only = !y.valid_when_open() || y == Yaku::Chiitoitsu;
+    let tsumo_only = matches!(
+        y,
+        Yaku::MenzenTsumo | Yaku::RinshanKaihou | Yaku::HaiteiRaoyue | Yaku::Tenhou | Yaku::Chiihou
+    );
+    let ron_only = matches!(y, Yaku::Chankan | Yaku::HouteiRaoyui);
+    let dealer_only = y == Yaku::Tenhou;
+
+    YakuRequirements {
+        closed_only,
+        tsumo_only,
+        ron_only,
+        dealer_only,
+    }
+}
+
 /// Detect yaku with full game context
 pub fn detect_yaku_with_context(
     structure: &HandStructure,
@@ -274,7 +403,7 @@ pub fn detect_yaku_with_context(
 
         HandStructure::Chiitoitsu { pairs } => {
             // Tsuuiisou (all honors) in chiitoitsu
-            if pairs.iter().all(|t| t.is_honor()) {
+            if is_tsuuiisou_chiitoi(pairs) {
                 yaku_list.push(Yaku::Tsuuiisou);
             }
             // Chinroutou (all terminals) in chiitoitsu
@@ -491,27 +620,23 @@ pub fn detect_yaku_with_context(
                     let mut concealed_triplets = 0;
                     for meld in melds {
                         match meld {
-                            Meld::Koutsu(tile, is_open_meld) => {
-                                // A triplet is concealed if:
-                                // 1. It's not an open pon
-                                // 2. For ron, the winning tile did NOT complete this triplet,
-                                //    OR the winning tile could have completed a sequence instead
-                                if !is_open_meld {
-                                    if context.win_type == WinType::Tsumo {
-                                        concealed_triplets += 1;
-                                    } else if let Some(wt) = context.winning_tile
-                                        && (*tile != wt || winning_tile_completes_sequence)
-                                    {
-                                        concealed_triplets += 1;
-                                    }
-                                }
-                            }
-                            Meld::Kan(_, kan_type) => {
-                                // Closed kans count as concealed triplets
-                                if !kan_type.is_open() {
+                            // A triplet is concealed if:
+                            // 1. It's not an open pon
+                            // 2. For ron, the winning tile did NOT complete this triplet,
+                            //    OR the winning tile could have completed a sequence instead
+                            Meld::Koutsu(tile, false) => {
+                                if context.win_type == WinType::Tsumo {
+                                    concealed_triplets += 1;
+                                } else if let Some(wt) = context.winning_tile
+                                    && (*tile != wt || winning_tile_completes_sequence)
+                                {
                                     concealed_triplets += 1;
                                 }
                             }
+                            // Closed kans count as concealed triplets
+                            Meld::Kan(_, kan_type) if !kan_type.is_open() => {
+                                concealed_triplets += 1;
+                            }
                             _ => {}
                         }
                     }
@@ -556,19 +681,42 @@ pub fn detect_yaku_with_context(
         }
     }
 
+    // Drop yaku the rule set doesn't award
+    yaku_list.retain(|&y| context.rules.allows(y, is_open));
+
     // Check for yakuman in final list
     let is_yakuman = yaku_list.iter().any(|y| y.is_yakuman());
 
     // Filter out invalid yaku for open hands and calculate han
-    let total_han: u8 = if is_open {
+    if is_open {
         yaku_list.retain(|y| y.valid_when_open());
-        yaku_list.iter().filter_map(|y| y.han_open()).sum()
+    }
+    let total_han = total_han(&yaku_list, is_open);
+
+    let dropped_open = if is_open && !has_yakuman {
+        dropped_open_yaku(&closed_only_candidates(structure, context))
     } else {
-        yaku_list.iter().map(|y| y.han()).sum()
+        Vec::new()
     };
 
+    // Every remaining yaku must be worth a defined han at this open/closed state
+    #[cfg(debug_assertions)]
+    {
+        let displayed: Option<u8> = yaku_list.iter().map(|y| y.effective_han(is_open)).sum();
+        debug_assert_eq!(
+            displayed,
+            Some(total_han),
+            "total_han out of sync with {yaku_list:?}"
+        );
+    }
+
     // Count dora with breakdown
-    let dora = count_dora_detailed(counts, context);
+    let mut dora = count_dora_detailed(counts, context);
+    let has_kan = matches!(
+        structure,
+        HandStructure::Standard { melds, .. } if melds.iter().any(|m| matches!(m, Meld::Kan(..)))
+    );
+    dora.regular += count_kan_dora(counts, context, has_kan);
 
     YakuResult {
         yaku_list,
@@ -578,9 +726,50 @@ pub fn detect_yaku_with_context(
         ura_dora: dora.ura,
         aka_dora: dora.aka,
         is_yakuman,
+        dropped_open,
     }
 }
 
+/// Yaku paired with the han to display for each
+///
+/// Normally every yaku in `yaku_list` at its open/closed han. With
+/// `context.merge_shousangen_yakuhai`, the dragon yakuhai are folded into the
+/// Shousangen entry, which then shows their combined han. The total is unchanged.
+pub fn displayed_yaku(result: &YakuResult, context: &GameContext) -> Vec<(Yaku, u8)> {
+    let is_dragon_yakuhai = |y: &Yaku| {
+        matches!(
+            y,
+            Yaku::Yakuhai(Honor::White) | Yaku::Yakuhai(Honor::Green) | Yaku::Yakuhai(Honor::Red)
+        )
+    };
+    let merge = context.merge_shousangen_yakuhai && result.yaku_list.contains(&Yaku::Shousangen);
+
+    let dragon_han: u8 = if merge {
+        result
+            .yaku_list
+            .iter()
+            .filter(|y| is_dragon_yakuhai(y))
+            .filter_map(|y| y.effective_han(context.is_open))
+            .sum()
+    } else {
+        0
+    };
+
+    result
+        .yaku_list
+        .iter()
+        .filter(|y| !(merge && is_dragon_yakuhai(y)))
+        .map(|y| {
+            let han = y.effective_han(context.is_open).unwrap_or(0);
+            if *y == Yaku::Shousangen {
+                (*y, han + dragon_han)
+            } else {
+                (*y, han)
+            }
+        })
+        .collect()
+}
+
 /// Detect yaku without game context (backwards compatibility)
 pub fn detect_yaku(structure: &HandStructure) -> YakuResult {
     // Create a minimal context
@@ -589,44 +778,275 @@ pub fn detect_yaku(structure: &HandStructure) -> YakuResult {
     detect_yaku_with_context(structure, &empty_counts, &dummy_context)
 }
 
-// ============ Helper Functions ============
+/// Union of every yaku that some interpretation of a complete hand can award
+///
+/// Each decomposition is tried with every distinct tile in the hand as the winning
+/// tile (or only the context's winning tile, if set). Yaku are returned in the order
+/// first found, without duplicates.
+pub fn all_possible_yaku(counts: &TileCounts, context: &GameContext) -> Vec<Yaku> {
+    all_possible_yaku_with_melds(counts, &[], context)
+}
+
+/// [`all_possible_yaku`] for a hand with called melds
+///
+/// `counts` holds the concealed tiles only; winning tile candidates are drawn from them.
+pub fn all_possible_yaku_with_melds(
+    counts: &TileCounts,
+    called_melds: &[Meld],
+    context: &GameContext,
+) -> Vec<Yaku> {
+    let structures = if called_melds.is_empty() {
+        decompose_hand(counts)
+    } else {
+        decompose_hand_with_melds(counts, called_melds)
+    };
 
-/// Collect all tiles from melds and pair
-fn collect_all_tiles(melds: &[Meld], pair: Tile) -> Vec<Tile> {
-    let mut tiles = vec![pair, pair];
+    let mut all_counts = counts.clone();
+    for tile in collect_meld_tiles(called_melds) {
+        *all_counts.entry(tile).or_insert(0) += 1;
+    }
+
+    let candidates: Vec<Tile> = match context.winning_tile {
+        Some(tile) => vec![tile],
+        None => {
+            let mut tiles: Vec<Tile> = counts
+                .iter()
+                .filter(|&(_, &count)| count > 0)
+                .map(|(tile, _)| *tile)
+                .collect();
+            tiles.sort();
+            tiles
+        }
+    };
 
-    for meld in melds {
-        match meld {
-            Meld::Koutsu(t, _) => {
-                tiles.push(*t);
-                tiles.push(*t);
-                tiles.push(*t);
-            }
-            Meld::Shuntsu(t, _) => {
-                tiles.push(*t);
-                if let Tile::Suited { suit, value } = t {
-                    tiles.push(Tile::suited(*suit, value + 1));
-                    tiles.push(Tile::suited(*suit, value + 2));
+    let mut union = Vec::new();
+    for tile in candidates {
+        let ctx = context.clone().with_winning_tile(tile);
+        for structure in &structures {
+            for yaku in detect_yaku_with_context(structure, &all_counts, &ctx).yaku_list {
+                if !union.contains(&yaku) {
+                    union.push(yaku);
                 }
             }
-            Meld::Kan(t, _) => {
-                tiles.push(*t);
-                tiles.push(*t);
-                tiles.push(*t);
-                tiles.push(*t);
+        }
+    }
+    union
+}
+
+/// Yaku of a tenpai hand split by whether they depend on the winning tile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YakuCertainty {
+    /// Yaku available on every winning tile (e.g., riichi, honitsu)
+    pub guaranteed: Vec<Yaku>,
+    /// Yaku available on some winning tiles but not others (e.g., pinfu, sanshoku)
+    pub wait_dependent: Vec<Yaku>,
+}
+
+/// Split the yaku of a tenpai hand (13 concealed tiles) into guaranteed and wait-dependent
+///
+/// For each winning tile the possible yaku are collected with `all_possible_yaku`.
+/// Guaranteed yaku appear for every winning tile; the rest are wait-dependent.
+/// A hand that is not tenpai has neither. Hands with called melds go through
+/// [`yaku_certainty_with_melds`].
+pub fn yaku_certainty(counts: &TileCounts, context: &GameContext) -> YakuCertainty {
+    yaku_certainty_with_melds(counts, &[], context)
+}
+
+/// [`yaku_certainty`] for a tenpai hand with called melds
+///
+/// `counts` holds the concealed tiles only (13 minus 3 per called meld); any other
+/// tile count has neither guaranteed nor wait-dependent yaku.
+pub fn yaku_certainty_with_melds(
+    counts: &TileCounts,
+    called_melds: &[Meld],
+    context: &GameContext,
+) -> YakuCertainty {
+    let tile_count: usize = counts.values().map(|&c| c as usize).sum();
+    let waits = if tile_count + 3 * called_melds.len() == 13 {
+        calculate_waits(counts)
+    } else {
+        Vec::new()
+    };
+
+    let per_tile: Vec<Vec<Yaku>> = waits
+        .into_iter()
+        .map(|tile| {
+            let mut complete = counts.clone();
+            *complete.entry(tile).or_insert(0) += 1;
+            all_possible_yaku_with_melds(
+                &complete,
+                called_melds,
+                &context.clone().with_winning_tile(tile),
+            )
+        })
+        .collect();
+
+    let mut guaranteed = Vec::new();
+    let mut wait_dependent = Vec::new();
+    for yaku in per_tile.iter().flatten() {
+        if guaranteed.contains(yaku) || wait_dependent.contains(yaku) {
+            continue;
+        }
+        if per_tile.iter().all(|list| list.contains(yaku)) {
+            guaranteed.push(*yaku);
+        } else {
+            wait_dependent.push(*yaku);
+        }
+    }
+
+    YakuCertainty {
+        guaranteed,
+        wait_dependent,
+    }
+}
+
+/// Most tile swaps `tiles_to_yaku` will search through
+const MAX_YAKU_SWAPS: usize = 2;
+
+/// Tile predicate used to prune the `tiles_to_yaku` search
+type TileFilter = fn(&Tile) -> bool;
+
+/// Find a minimal set of tile swaps that makes a complete hand award `target`
+///
+/// Returns the number of swaps and each swap as `(removed, added)`. The search is
+/// breadth-first and bounded to a couple of swaps, so `None` means either that no
+/// solution exists within the bound or that `target` isn't supported. Supported
+/// targets are Tanyao, Honitsu and Chanta.
+pub fn tiles_to_yaku(counts: &TileCounts, target: Yaku) -> Option<(usize, Vec<(Tile, Tile)>)> {
+    // Prune the search to swaps that can move the hand toward the target
+    let (removable, addable): (TileFilter, TileFilter) = match target {
+        Yaku::Tanyao => (|t| !t.is_simple(), |t| t.is_simple()),
+        Yaku::Honitsu => (|t| !t.is_honor(), |_| true),
+        Yaku::Chanta => (
+            |_| true,
+            |t| match t {
+                Tile::Suited { value, .. } => *value <= 3 || *value >= 7,
+                Tile::Honor(_) => true,
+            },
+        ),
+        _ => return None,
+    };
+
+    let has_target = |hand: &TileCounts| {
+        decompose_hand(hand)
+            .iter()
+            .any(|s| detect_yaku(s).yaku_list.contains(&target))
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    let mut frontier = vec![(counts.clone(), Vec::new())];
+
+    for swaps in 0..=MAX_YAKU_SWAPS {
+        if let Some((_, path)) = frontier.iter().find(|(hand, _)| has_target(hand)) {
+            return Some((swaps, path.clone()));
+        }
+        if swaps == MAX_YAKU_SWAPS {
+            break;
+        }
+
+        let mut next = Vec::new();
+        for (hand, path) in &frontier {
+            for (&removed, _) in hand.iter().filter(|&(t, &c)| c > 0 && removable(t)) {
+                for added in crate::tile::all_tiles() {
+                    if added == removed
+                        || !addable(&added)
+                        || hand.get(&added).copied().unwrap_or(0) >= 4
+                    {
+                        continue;
+                    }
+
+                    let mut swapped = hand.clone();
+                    *swapped.get_mut(&removed).unwrap() -= 1;
+                    if swapped[&removed] == 0 {
+                        swapped.remove(&removed);
+                    }
+                    *swapped.entry(added).or_insert(0) += 1;
+
+                    let mut key: Vec<(Tile, u8)> = swapped.iter().map(|(t, c)| (*t, *c)).collect();
+                    key.sort();
+                    if seen.insert(key) {
+                        let mut swap_path = path.clone();
+                        swap_path.push((removed, added));
+                        next.push((swapped, swap_path));
+                    }
+                }
             }
         }
+        frontier = next;
+    }
+
+    None
+}
+
+// ============ Helper Functions ============
+
+/// Yaku from `yaku_before_filter` that an open hand cannot have
+pub fn dropped_open_yaku(yaku_before_filter: &[Yaku]) -> Vec<Yaku> {
+    yaku_before_filter
+        .iter()
+        .copied()
+        .filter(|y| !y.valid_when_open())
+        .collect()
+}
+
+/// Closed-only yaku the hand would earn if every meld were concealed
+///
+/// Used to explain what an open hand lost by calling.
+fn closed_only_candidates(structure: &HandStructure, context: &GameContext) -> Vec<Yaku> {
+    let mut candidates = Vec::new();
+
+    if context.is_riichi {
+        if context.is_double_riichi {
+            candidates.push(Yaku::DoubleRiichi);
+        } else {
+            candidates.push(Yaku::Riichi);
+        }
+        if context.is_ippatsu {
+            candidates.push(Yaku::Ippatsu);
+        }
+    }
+    if context.win_type == WinType::Tsumo {
+        candidates.push(Yaku::MenzenTsumo);
+    }
+
+    if let HandStructure::Standard { melds, pair } = structure {
+        let closed_melds: Vec<Meld> = melds
+            .iter()
+            .map(|m| match m {
+                Meld::Shuntsu(t, _) => Meld::Shuntsu(*t, false),
+                Meld::Koutsu(t, _) => Meld::Koutsu(*t, false),
+                Meld::Kan(t, _) => Meld::Kan(*t, KanType::Closed),
+            })
+            .collect();
+        let closed = HandStructure::Standard {
+            melds: closed_melds,
+            pair: *pair,
+        };
+        let mut closed_context = context.clone();
+        closed_context.is_open = false;
+
+        if let Some(winning_tile) = context.winning_tile
+            && is_pinfu(&closed, winning_tile, &closed_context)
+        {
+            candidates.push(Yaku::Pinfu);
+        }
+        if let HandStructure::Standard { melds, .. } = &closed
+            && let Some(peikou) = check_peikou(melds)
+        {
+            candidates.push(peikou);
+        }
     }
 
-    tiles
+    candidates
 }
 
 /// Check for iipeikou (2 identical sequences) or ryanpeikou (2 pairs of identical sequences)
 fn check_peikou(melds: &[Meld]) -> Option<Yaku> {
+    // Only concealed sequences count: an open chi never completes a peikou
     let sequences: Vec<_> = melds
         .iter()
         .filter_map(|m| match m {
-            Meld::Shuntsu(t, _) => Some(*t),
+            Meld::Shuntsu(t, false) => Some(*t),
             _ => None,
         })
         .collect();
@@ -665,22 +1085,14 @@ fn check_yakuhai(melds: &[Meld], context: &GameContext) -> Vec<Yaku> {
             _ => None,
         };
 
-        if let Some(honor) = honor {
-            match honor {
-                // Dragons are always yakuhai
-                Honor::White | Honor::Green | Honor::Red => {
-                    result.push(Yaku::Yakuhai(*honor));
-                }
-                // Winds are yakuhai if they match round or seat wind
-                // Double wind (both round and seat) = 2 yakuhai
-                Honor::East | Honor::South | Honor::West | Honor::North => {
-                    if *honor == context.round_wind {
-                        result.push(Yaku::Yakuhai(*honor));
-                    }
-                    if *honor == context.seat_wind {
-                        result.push(Yaku::Yakuhai(*honor));
-                    }
-                }
+        if let Some(&honor) = honor
+            && context.is_value_honor(honor)
+        {
+            result.push(Yaku::Yakuhai(honor));
+
+            // Double wind (both round and seat) = 2 yakuhai
+            if honor == context.round_wind && honor == context.seat_wind {
+                result.push(Yaku::Yakuhai(honor));
             }
         }
     }
@@ -961,6 +1373,11 @@ fn check_tsuuiisou(melds: &[Meld], pair: Tile) -> bool {
     })
 }
 
+/// Check for Tsuuiisou in seven pairs form: every one of the seven pairs is an honor
+pub fn is_tsuuiisou_chiitoi(pairs: &[Tile]) -> bool {
+    pairs.len() == 7 && pairs.iter().all(|t| t.is_honor())
+}
+
 /// Check for Chinroutou (All Terminals)
 fn check_chinroutou(melds: &[Meld], pair: Tile) -> bool {
     if !pair.is_terminal() {
@@ -1141,7 +1558,6 @@ fn check_shousangen(melds: &[Meld], pair: Tile) -> bool {
 mod tests {
     use super::*;
     use crate::context::{GameContext, WinType};
-    use crate::hand::decompose_hand;
     use crate::parse::{parse_hand, to_counts};
 
     /// Helper to get yaku for a hand string (no context)
@@ -1213,6 +1629,19 @@ mod tests {
         assert!(has_yaku(&results, Yaku::SanshokuDoujun));
     }
 
+    #[test]
+    fn test_sanshoku_with_doubled_sequence() {
+        // 123m appears twice: sanshoku and iipeikou are each awarded exactly once
+        let results = get_yaku("112233m123p123s55p");
+        assert_eq!(results.len(), 1);
+
+        let result = &results[0];
+        let count = |yaku| result.yaku_list.iter().filter(|&&y| y == yaku).count();
+        assert_eq!(count(Yaku::SanshokuDoujun), 1);
+        assert_eq!(count(Yaku::Iipeikou), 1);
+        assert_eq!(result.total_han, 3);
+    }
+
     #[test]
     fn test_ittsu() {
         let results = get_yaku("123456789m111p22z");
@@ -1225,6 +1654,28 @@ mod tests {
         assert!(has_yaku(&results, Yaku::Chiitoitsu));
     }
 
+    #[test]
+    fn test_tsuuiisou_chiitoi() {
+        let results = get_yaku("11223344556677z");
+        assert!(has_yaku(&results, Yaku::Tsuuiisou));
+
+        let honors = parse_hand("1234567z").unwrap();
+        assert!(is_tsuuiisou_chiitoi(&honors));
+
+        let one_suited = parse_hand("9m123456z").unwrap();
+        assert!(!is_tsuuiisou_chiitoi(&one_suited));
+    }
+
+    #[test]
+    fn test_ryuuiisou_without_green_dragon() {
+        let results = get_yaku("234234666888s44s");
+        assert!(has_yaku(&results, Yaku::Ryuuiisou));
+
+        // 5s isn't green, whether in a sequence or a triplet
+        assert!(!has_yaku(&get_yaku("234345666888s44s"), Yaku::Ryuuiisou));
+        assert!(!has_yaku(&get_yaku("234555666888s44s"), Yaku::Ryuuiisou));
+    }
+
     #[test]
     fn test_honitsu() {
         let results = get_yaku("123456789m11177z");
@@ -1232,6 +1683,210 @@ mod tests {
         assert!(!has_yaku(&results, Yaku::Chinitsu));
     }
 
+    #[test]
+    fn test_no_kuitan_rejects_open_tanyao() {
+        let open = GameContext::new(WinType::Ron, Honor::East, Honor::South).open();
+        let results = get_yaku_with_context("234567m234p345s88s", &open);
+        assert!(has_yaku(&results, Yaku::Tanyao));
+
+        let results = get_yaku_with_context(
+            "234567m234p345s88s",
+            &open.clone().with_allow_open_tanyao(true),
+        );
+        assert!(results.iter().all(|r| r.yaku_list == vec![Yaku::Tanyao]));
+
+        // Without kuitan, open tanyao is the hand's only yaku, so it is left yakuless
+        for context in [
+            open.clone().no_kuitan(),
+            open.clone().with_allow_open_tanyao(false),
+        ] {
+            let results = get_yaku_with_context("234567m234p345s88s", &context);
+            assert!(!has_yaku(&results, Yaku::Tanyao));
+            assert!(results.iter().all(|r| r.yaku_list.is_empty()));
+            assert!(results.iter().all(|r| r.total_han == 0));
+        }
+
+        // Closed tanyao is unaffected
+        let closed = GameContext::new(WinType::Ron, Honor::East, Honor::South).no_kuitan();
+        let results = get_yaku_with_context("234567m234p345s88s", &closed);
+        assert!(has_yaku(&results, Yaku::Tanyao));
+    }
+
+    #[test]
+    fn test_disabled_yaku_not_awarded() {
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .riichi()
+            .disable_yaku(Yaku::Riichi);
+        let results = get_yaku_with_context("234567m234p345s88s", &context);
+        assert!(!has_yaku(&results, Yaku::Riichi));
+        assert!(has_yaku(&results, Yaku::MenzenTsumo));
+    }
+
+    #[test]
+    fn test_tiles_to_yaku_tanyao_single_terminal() {
+        // 123m is the only group with a terminal: swapping 1m for 4m makes 234m
+        let counts = to_counts(&parse_hand("123567m234p345s88s").unwrap());
+        let (swaps, changes) = tiles_to_yaku(&counts, Yaku::Tanyao).unwrap();
+
+        assert_eq!(swaps, 1);
+        assert_eq!(
+            changes,
+            vec![(Tile::suited(Suit::Man, 1), Tile::suited(Suit::Man, 4))]
+        );
+    }
+
+    #[test]
+    fn test_tiles_to_yaku_already_satisfied() {
+        let counts = to_counts(&parse_hand("234567m234p345s88s").unwrap());
+        assert_eq!(tiles_to_yaku(&counts, Yaku::Tanyao), Some((0, vec![])));
+    }
+
+    #[test]
+    fn test_tiles_to_yaku_unsupported_target() {
+        let counts = to_counts(&parse_hand("234567m234p345s88s").unwrap());
+        assert_eq!(tiles_to_yaku(&counts, Yaku::Riichi), None);
+    }
+
+    #[test]
+    fn test_two_suit_hand_no_flush() {
+        let results = get_yaku("123456789m11p789s");
+        assert!(!has_yaku(&results, Yaku::Honitsu));
+        assert!(!has_yaku(&results, Yaku::Chinitsu));
+
+        // Honors don't rescue a second suit
+        let tiles = parse_hand("123456m789p11122z").unwrap();
+        assert_eq!(check_flush_tiles(&tiles), None);
+    }
+
+    #[test]
+    fn test_no_iipeikou_with_open_chi() {
+        // Called chi of 123m matches the concealed 123m, which is not iipeikou
+        let (structures, counts) = structures_with_melds("(123m)123m456p555z11s");
+        assert!(!structures.is_empty());
+
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).open();
+        for structure in &structures {
+            let result = detect_yaku_with_context(structure, &counts, &context);
+            assert!(!result.yaku_list.contains(&Yaku::Iipeikou));
+            assert!(result.yaku_list.contains(&Yaku::Yakuhai(Honor::White)));
+        }
+
+        let man_1 = Tile::suited(Suit::Man, 1);
+        let melds = [Meld::Shuntsu(man_1, true), Meld::Shuntsu(man_1, false)];
+        assert_eq!(check_peikou(&melds), None);
+    }
+
+    /// Decompositions of a hand with called melds, and the counts of all its tiles
+    fn structures_with_melds(hand: &str) -> (Vec<HandStructure>, TileCounts) {
+        use crate::hand::decompose_hand_with_melds;
+        use crate::parse::parse_hand_with_aka;
+
+        let parsed = parse_hand_with_aka(hand).unwrap();
+        let called_melds: Vec<_> = parsed
+            .called_melds
+            .iter()
+            .map(|cm| cm.meld.clone())
+            .collect();
+
+        let structures = decompose_hand_with_melds(&parsed.concealed_counts(), &called_melds);
+        (structures, parsed.all_counts())
+    }
+
+    /// Yaku for a hand with called melds, scored as an open ron
+    fn open_hand_yaku(hand: &str) -> YakuResult {
+        let (structures, counts) = structures_with_melds(hand);
+        assert_eq!(structures.len(), 1);
+
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).open();
+        detect_yaku_with_context(&structures[0], &counts, &context)
+    }
+
+    #[test]
+    fn test_open_reductions_keep_total_han_in_sync() {
+        // Ittsu 1 (open) + honitsu 2 (open) + haku 1
+        let result = open_hand_yaku("(123m)456789m555z99m");
+        assert!(result.yaku_list.contains(&Yaku::Ittsu));
+        assert!(result.yaku_list.contains(&Yaku::Honitsu));
+        assert_eq!(result.total_han, 4);
+        assert_eq!(result.total_han, total_han(&result.yaku_list, true));
+
+        // Ittsu 1 (open) + chinitsu 5 (open)
+        let result = open_hand_yaku("(123m)456789m111m99m");
+        assert!(result.yaku_list.contains(&Yaku::Chinitsu));
+        assert_eq!(result.total_han, 6);
+        assert_eq!(result.total_han, total_han(&result.yaku_list, true));
+    }
+
+    #[test]
+    fn test_dropped_open_yaku() {
+        // Structurally pinfu + iipeikou, but the 234m chi opens the hand
+        let (structures, counts) = structures_with_melds("(234m)234m567p678s55s");
+        assert_eq!(structures.len(), 1);
+
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South)
+            .open()
+            .with_winning_tile(Tile::suited(Suit::Sou, 8));
+        let result = detect_yaku_with_context(&structures[0], &counts, &context);
+
+        assert_eq!(result.yaku_list, vec![Yaku::Tanyao]);
+        assert!(result.dropped_open.contains(&Yaku::Pinfu));
+        assert!(result.dropped_open.contains(&Yaku::Iipeikou));
+
+        assert_eq!(
+            dropped_open_yaku(&[Yaku::Riichi, Yaku::Tanyao, Yaku::Pinfu]),
+            vec![Yaku::Riichi, Yaku::Pinfu]
+        );
+    }
+
+    #[test]
+    fn test_open_chinitsu_with_called_chi() {
+        // The flush check sees the called chi's tiles: all manzu, no honors
+        let result = open_hand_yaku("(234m)234m567m888m99m");
+        assert!(result.yaku_list.contains(&Yaku::Chinitsu));
+        assert!(!result.yaku_list.contains(&Yaku::Honitsu));
+        assert_eq!(result.total_han, 5);
+    }
+
+    #[test]
+    fn test_open_honitsu_with_called_meld() {
+        // Called pon of 1m; the flush check has to see the meld's tiles too
+        let result = open_hand_yaku("(111m)234567m789m11z");
+        assert_eq!(result.yaku_list, vec![Yaku::Honitsu]);
+        assert_eq!(result.total_han, 2);
+    }
+
+    #[test]
+    fn test_open_chanta_with_called_chi() {
+        // The called 789p chi has to count as a terminal group
+        let result = open_hand_yaku("(789p)123m999m789s22z");
+        assert_eq!(result.yaku_list, vec![Yaku::Chanta]);
+        assert_eq!(result.total_han, 1);
+    }
+
+    #[test]
+    fn test_open_junchan_with_called_chi() {
+        let result = open_hand_yaku("(789p)123m999m789s11s");
+        assert_eq!(result.yaku_list, vec![Yaku::Junchan]);
+        assert_eq!(result.total_han, 2);
+    }
+
+    #[test]
+    fn test_closed_kan_keeps_hand_closed() {
+        let (structures, counts) = structures_with_melds("[1111m]234m567p789s11z");
+        assert!(!structures.is_empty());
+        assert!(matches!(
+            &structures[0],
+            HandStructure::Standard { melds, .. } if melds.iter().all(|m| !m.is_open())
+        ));
+
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .riichi()
+            .with_winning_tile(Tile::suited(Suit::Pin, 5));
+        let result = detect_yaku_with_context(&structures[0], &counts, &context);
+        assert!(result.yaku_list.contains(&Yaku::Riichi));
+        assert!(result.yaku_list.contains(&Yaku::MenzenTsumo));
+    }
+
     #[test]
     fn test_chinitsu() {
         let results = get_yaku("11123456789999m");
@@ -1268,6 +1923,42 @@ mod tests {
         assert!(has_yaku(&results, Yaku::Toitoi));
     }
 
+    #[test]
+    fn test_effective_han_open_and_closed() {
+        assert_eq!(effective_han(Yaku::Chinitsu, false), Some(6));
+        assert_eq!(effective_han(Yaku::Chinitsu, true), Some(5));
+        assert_eq!(effective_han(Yaku::Riichi, false), Some(1));
+        assert_eq!(effective_han(Yaku::Riichi, true), None);
+        assert_eq!(effective_han(Yaku::Tanyao, false), Some(1));
+        assert_eq!(effective_han(Yaku::Tanyao, true), Some(1));
+
+        // The method and the free function are the same rule
+        assert_eq!(Yaku::Riichi.effective_han(true), None);
+        assert_eq!(total_han(&[Yaku::Riichi, Yaku::Chinitsu], true), 5);
+    }
+
+    #[test]
+    fn test_yaku_requirements() {
+        let menzen_tsumo = yaku_requirements(Yaku::MenzenTsumo);
+        assert!(menzen_tsumo.closed_only && menzen_tsumo.tsumo_only);
+        assert!(!menzen_tsumo.ron_only && !menzen_tsumo.dealer_only);
+
+        let chankan = yaku_requirements(Yaku::Chankan);
+        assert!(chankan.ron_only && !chankan.closed_only && !chankan.tsumo_only);
+
+        let tenhou = yaku_requirements(Yaku::Tenhou);
+        assert!(tenhou.dealer_only && tenhou.tsumo_only && tenhou.closed_only);
+
+        assert!(yaku_requirements(Yaku::Chiitoitsu).closed_only);
+        assert!(!yaku_requirements(Yaku::Suuankou).tsumo_only);
+        assert!(yaku_requirements(Yaku::Pinfu).closed_only);
+        assert_eq!(yaku_requirements(Yaku::Tanyao), YakuRequirements::default());
+        assert_eq!(
+            yaku_requirements(Yaku::Yakuhai(Honor::Red)),
+            YakuRequirements::default()
+        );
+    }
+
     // ===== Context-Aware Yaku Tests =====
 
     #[test]
@@ -1370,6 +2061,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_total_han_closed_vs_open() {
+        let yaku = vec![Yaku::Ittsu, Yaku::SanshokuDoujun];
+        assert_eq!(total_han(&yaku, false), 4);
+        assert_eq!(total_han(&yaku, true), 2);
+    }
+
+    #[test]
+    fn test_total_han_open_ignores_closed_only_yaku() {
+        let yaku = vec![Yaku::Riichi, Yaku::Pinfu, Yaku::Ittsu, Yaku::Tanyao];
+        assert_eq!(total_han(&yaku, false), 5);
+        assert_eq!(total_han(&yaku, true), 2);
+    }
+
+    #[test]
+    fn test_total_han_matches_detect_yaku() {
+        let context_closed = GameContext::new(WinType::Ron, Honor::East, Honor::East);
+        let context_open = context_closed.clone().open();
+
+        for context in [&context_closed, &context_open] {
+            for result in get_yaku_with_context("123456789m11177z", context) {
+                assert_eq!(
+                    result.total_han,
+                    total_han(&result.yaku_list, context.is_open)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_displayed_yaku_shousangen_lists_dragons() {
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South);
+        let results = get_yaku_with_context("123m456p55566677z", &context);
+        let result = results
+            .iter()
+            .find(|r| r.yaku_list.contains(&Yaku::Shousangen))
+            .unwrap();
+
+        let shown = displayed_yaku(result, &context);
+        assert!(shown.contains(&(Yaku::Shousangen, 2)));
+        assert!(shown.contains(&(Yaku::Yakuhai(Honor::White), 1)));
+        assert!(shown.contains(&(Yaku::Yakuhai(Honor::Green), 1)));
+        assert_eq!(shown.iter().map(|(_, h)| *h).sum::<u8>(), result.total_han);
+    }
+
+    #[test]
+    fn test_displayed_yaku_shousangen_merged() {
+        let context =
+            GameContext::new(WinType::Ron, Honor::East, Honor::South).merge_shousangen_yakuhai();
+        let results = get_yaku_with_context("123m456p55566677z", &context);
+        let result = results
+            .iter()
+            .find(|r| r.yaku_list.contains(&Yaku::Shousangen))
+            .unwrap();
+
+        let shown = displayed_yaku(result, &context);
+        assert!(shown.contains(&(Yaku::Shousangen, 4)));
+        assert!(!shown.iter().any(|(y, _)| matches!(y, Yaku::Yakuhai(_))));
+        assert_eq!(shown.iter().map(|(_, h)| *h).sum::<u8>(), result.total_han);
+    }
+
     // ===== Dora Tests =====
 
     #[test]
@@ -1413,6 +2165,45 @@ mod tests {
         assert_eq!(result.total_han_with_dora(), result.total_han + 3);
     }
 
+    #[test]
+    fn test_kan_dora_needs_kan_and_rinshan() {
+        use crate::hand::decompose_hand_with_melds;
+        use crate::parse::parse_hand_with_aka;
+
+        // Kan dora indicator 1p makes 2p dora (two copies in hand)
+        let yaku_for = |hand: &str, context: &GameContext| {
+            let parsed = parse_hand_with_aka(hand).unwrap();
+            let called_melds: Vec<_> = parsed
+                .called_melds
+                .iter()
+                .map(|cm| cm.meld.clone())
+                .collect();
+            let structure = if called_melds.is_empty() {
+                decompose_hand(&parsed.concealed_counts()).remove(0)
+            } else {
+                decompose_hand_with_melds(&parsed.concealed_counts(), &called_melds).remove(0)
+            };
+            detect_yaku_with_context(&structure, &parsed.all_counts(), context)
+        };
+        let base = GameContext::new(WinType::Tsumo, Honor::East, Honor::South)
+            .with_winning_tile(Tile::suited(Suit::Pin, 2))
+            .with_kan_dora(vec![Tile::suited(Suit::Pin, 1)])
+            .rinshan_reveals_dora();
+
+        let kan_hand = "[1111m]234m567s789s22p";
+        assert_eq!(yaku_for(kan_hand, &base.clone().rinshan()).regular_dora, 2);
+        assert_eq!(yaku_for(kan_hand, &base).regular_dora, 0);
+        assert_eq!(
+            yaku_for("111m234m567s789s22p", &base.clone().rinshan()).regular_dora,
+            0
+        );
+
+        // Without the flag, kan dora always count
+        let mut always = base.clone();
+        always.rinshan_reveals_dora = false;
+        assert_eq!(yaku_for(kan_hand, &always).regular_dora, 2);
+    }
+
     // ===== Situational Yaku Tests =====
 
     #[test]
@@ -1650,6 +2441,72 @@ mod tests {
         assert!(!has_yaku(&results, Yaku::Pinfu), "Open hand can't be pinfu");
     }
 
+    #[test]
+    fn test_all_possible_yaku_closed_includes_pinfu() {
+        let tiles = parse_hand("123456789m234s55p").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South);
+
+        let union = all_possible_yaku(&counts, &context);
+        assert!(union.contains(&Yaku::Pinfu));
+        assert!(union.contains(&Yaku::Ittsu));
+    }
+
+    #[test]
+    fn test_all_possible_yaku_open_never_pinfu() {
+        let tiles = parse_hand("123456789m234s55p").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Tsumo, Honor::East, Honor::South).open();
+
+        let union = all_possible_yaku(&counts, &context);
+        assert!(!union.contains(&Yaku::Pinfu));
+        assert!(union.contains(&Yaku::Ittsu));
+    }
+
+    #[test]
+    fn test_yaku_certainty_pinfu_wait_dependent() {
+        // 2223m: 1m/4m are ryanmen (pinfu), 3m makes a 222m triplet (no pinfu)
+        let tiles = parse_hand("2223m456789p234s").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).riichi();
+
+        let certainty = yaku_certainty(&counts, &context);
+
+        assert!(certainty.guaranteed.contains(&Yaku::Riichi));
+        assert!(certainty.wait_dependent.contains(&Yaku::Pinfu));
+        assert!(!certainty.guaranteed.contains(&Yaku::Pinfu));
+    }
+
+    #[test]
+    fn test_yaku_certainty_with_called_meld() {
+        // Haku pon; 23m waits on 1m/4m, and only 1m completes the ittsu
+        let tiles = parse_hand("23456789m55m").unwrap();
+        let counts = to_counts(&tiles);
+        let melds = [Meld::koutsu_open(Tile::honor(Honor::White))];
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).open();
+
+        let certainty = yaku_certainty_with_melds(&counts, &melds, &context);
+
+        assert!(certainty.guaranteed.contains(&Yaku::Yakuhai(Honor::White)));
+        assert!(certainty.guaranteed.contains(&Yaku::Honitsu));
+        assert_eq!(certainty.wait_dependent, vec![Yaku::Ittsu]);
+
+        // The concealed tiles alone are not a 13-tile hand
+        assert!(yaku_certainty(&counts, &context).guaranteed.is_empty());
+    }
+
+    #[test]
+    fn test_yaku_certainty_not_tenpai() {
+        let tiles = parse_hand("1357m2468p1357s1z").unwrap();
+        let counts = to_counts(&tiles);
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::South).riichi();
+
+        let certainty = yaku_certainty(&counts, &context);
+
+        assert!(certainty.guaranteed.is_empty());
+        assert!(certainty.wait_dependent.is_empty());
+    }
+
     #[test]
     fn test_pinfu_no_winning_tile_no_pinfu() {
         // Without winning tile set, pinfu cannot be detected
@@ -1728,6 +2585,24 @@ mod tests {
         assert!(!has_yaku(&results_ron, Yaku::SanAnkou));
     }
 
+    #[test]
+    fn test_suuankou_tanki_ron() {
+        let hand_str = "111m222p333s444s55z";
+
+        // Ron on the pair: no triplet was completed by the discard
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::East)
+            .with_winning_tile(Tile::honor(Honor::White));
+        let results = get_yaku_with_context(hand_str, &context);
+        assert!(has_yaku(&results, Yaku::Suuankou));
+
+        // Ron on a triplet tile opens that triplet: sanankou + toitoi instead
+        let context = GameContext::new(WinType::Ron, Honor::East, Honor::East)
+            .with_winning_tile(Tile::suited(Suit::Man, 1));
+        let results = get_yaku_with_context(hand_str, &context);
+        assert!(!has_yaku(&results, Yaku::Suuankou));
+        assert!(has_yaku(&results, Yaku::SanAnkou));
+    }
+
     #[test]
     fn test_suu_kantsu() {
         use crate::hand::decompose_hand_with_melds;
@@ -1804,4 +2679,29 @@ mod tests {
         assert!(!result.yaku_list.contains(&Yaku::SuuKantsu));
         assert!(!result.is_yakuman);
     }
+
+    // ===== Display Tests =====
+
+    #[test]
+    fn test_yaku_display_names() {
+        assert_eq!(Yaku::Riichi.to_string(), "Riichi");
+        assert_eq!(Yaku::Tanyao.to_string(), "Tanyao");
+        assert_eq!(Yaku::Pinfu.to_string(), "Pinfu");
+        assert_eq!(Yaku::SanAnkou.to_string(), "San Ankou");
+        assert_eq!(Yaku::KokushiMusou.to_string(), "Kokushi Musou");
+        assert_eq!(Yaku::Suuankou.to_string(), "Suuankou");
+        assert_eq!(Yaku::Daisangen.to_string(), "Daisangen");
+        assert_eq!(Yaku::Kokushi13Wait.to_string(), "Kokushi 13-Wait");
+    }
+
+    #[test]
+    fn test_yaku_display_yakuhai() {
+        assert_eq!(Yaku::Yakuhai(Honor::East).to_string(), "Yakuhai (East)");
+        assert_eq!(Yaku::Yakuhai(Honor::South).to_string(), "Yakuhai (South)");
+        assert_eq!(Yaku::Yakuhai(Honor::West).to_string(), "Yakuhai (West)");
+        assert_eq!(Yaku::Yakuhai(Honor::North).to_string(), "Yakuhai (North)");
+        assert_eq!(Yaku::Yakuhai(Honor::White).to_string(), "Yakuhai (Haku)");
+        assert_eq!(Yaku::Yakuhai(Honor::Green).to_string(), "Yakuhai (Hatsu)");
+        assert_eq!(Yaku::Yakuhai(Honor::Red).to_string(), "Yakuhai (Chun)");
+    }
 }
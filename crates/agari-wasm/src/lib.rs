@@ -9,13 +9,24 @@ use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 use agari::context::{GameContext, WinType};
+use agari::furiten::is_furiten;
 use agari::hand::{HandStructure, decompose_hand, decompose_hand_with_melds};
-use agari::parse::TileCounts;
+use agari::parse::{ParseError, TileCounts};
 use agari::parse::{parse_hand_with_aka, to_counts};
-use agari::scoring::{ScoringResult, calculate_score};
-use agari::shanten::{ShantenResult, UkeireResult, calculate_shanten_with_melds, calculate_ukeire_with_melds};
-use agari::tile::{Honor, Tile};
-use agari::yaku::{Yaku, YakuResult, detect_yaku_with_context};
+use agari::request::{
+    ScoreError, ScoreRequest, build_context, parse_request_hand, validate_request,
+};
+use agari::scoring::{
+    Interpretation, ScoreSummary, ScoringResult, calculate_nagashi_mangan, compare_interpretations,
+    rank_interpretations,
+};
+use agari::shanten::{
+    ShantenResult, UkeireResult, best_discards, calculate_shanten_with_melds,
+    calculate_ukeire_with_melds,
+};
+use agari::tile::Tile;
+use agari::wait::calculate_waits;
+use agari::yaku::YakuResult;
 
 /// Initialize panic hook for better error messages in the browser console
 #[wasm_bindgen(start)]
@@ -27,46 +38,13 @@ pub fn init() {
 // Request/Response types for JavaScript interop
 // ============================================================================
 
-/// Input for scoring a hand from JavaScript
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScoreRequest {
-    /// Hand string in Agari notation (e.g., "123m456p789s11122z")
-    pub hand: String,
-    /// Winning tile (optional, will be inferred if not provided)
-    pub winning_tile: Option<String>,
-    /// Whether the win was by self-draw (tsumo)
-    pub is_tsumo: bool,
-    /// Whether riichi was declared
-    pub is_riichi: bool,
-    /// Whether double riichi was declared
-    pub is_double_riichi: bool,
-    /// Whether ippatsu (win within one turn of riichi)
-    pub is_ippatsu: bool,
-    /// Round wind: "east", "south", "west", "north"
-    pub round_wind: String,
-    /// Seat wind: "east", "south", "west", "north"
-    pub seat_wind: String,
-    /// Dora indicator tiles (e.g., ["1m", "5z"])
-    pub dora_indicators: Vec<String>,
-    /// Ura dora indicator tiles
-    pub ura_dora_indicators: Vec<String>,
-    /// Whether won on the last tile (haitei/houtei)
-    pub is_last_tile: bool,
-    /// Whether won on kan replacement tile (rinshan)
-    pub is_rinshan: bool,
-    /// Whether ron on another player's added kan (chankan)
-    pub is_chankan: bool,
-    /// Whether tenhou (dealer first draw win)
-    pub is_tenhou: bool,
-    /// Whether chiihou (non-dealer first draw win)
-    pub is_chiihou: bool,
-}
-
 /// Scoring result returned to JavaScript
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoreResponse {
     pub success: bool,
     pub error: Option<String>,
+    /// Stable machine-readable error code (see `ScoreError::code`)
+    pub error_code: Option<String>,
     pub result: Option<ScoringOutput>,
 }
 
@@ -124,6 +102,8 @@ pub struct PaymentInfo {
     pub from_discarder: Option<u32>,
     pub from_dealer: Option<u32>,
     pub from_non_dealer: Option<u32>,
+    /// `total` plus 1000 per riichi stick collected
+    pub total_with_sticks: u32,
 }
 
 /// Fu breakdown for display
@@ -137,6 +117,24 @@ pub struct FuBreakdownInfo {
     pub wait: u8,
     pub raw_total: u8,
     pub rounded: u8,
+    pub floored_to_open_minimum: bool,
+}
+
+/// Nagashi Mangan result: `payment` is absent when the pond doesn't qualify
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NagashiResponse {
+    pub success: bool,
+    pub error: Option<String>,
+    pub payment: Option<PaymentInfo>,
+}
+
+/// Furiten check result: `waits` is empty when the hand isn't tenpai
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuritenResponse {
+    pub success: bool,
+    pub error: Option<String>,
+    pub furiten: bool,
+    pub waits: Vec<String>,
 }
 
 /// Shanten calculation result
@@ -147,6 +145,8 @@ pub struct ShantenResponse {
     pub shanten: Option<i8>,
     pub best_type: Option<String>,
     pub description: Option<String>,
+    /// What is holding the hand back (absent once tenpai)
+    pub bottleneck: Option<String>,
 }
 
 /// Ukeire (tile acceptance) result
@@ -166,6 +166,31 @@ pub struct UkeireTileInfo {
     pub available: u8,
 }
 
+/// Discard analysis result, best discard first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestDiscardsResponse {
+    pub success: bool,
+    pub error: Option<String>,
+    pub discards: Option<Vec<DiscardInfo>>,
+}
+
+/// One candidate discard and the ukeire it leaves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscardInfo {
+    pub tile: String,
+    pub shanten: i8,
+    pub tiles: Vec<UkeireTileInfo>,
+    pub total_count: u8,
+}
+
+/// Winning tiles of a tenpai hand: `waits` is empty when the hand isn't tenpai
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitsResponse {
+    pub success: bool,
+    pub error: Option<String>,
+    pub waits: Option<Vec<String>>,
+}
+
 // ============================================================================
 // WASM-exported functions
 // ============================================================================
@@ -181,6 +206,7 @@ pub fn score_hand(request_js: JsValue) -> JsValue {
             return serde_wasm_bindgen::to_value(&ScoreResponse {
                 success: false,
                 error: Some(format!("Failed to parse request: {}", e)),
+                error_code: Some("invalid_request".to_string()),
                 result: None,
             })
             .unwrap();
@@ -191,12 +217,14 @@ pub fn score_hand(request_js: JsValue) -> JsValue {
         Ok(output) => serde_wasm_bindgen::to_value(&ScoreResponse {
             success: true,
             error: None,
+            error_code: None,
             result: Some(output),
         })
         .unwrap(),
         Err(e) => serde_wasm_bindgen::to_value(&ScoreResponse {
             success: false,
-            error: Some(e),
+            error: Some(e.to_string()),
+            error_code: Some(e.code().to_string()),
             result: None,
         })
         .unwrap(),
@@ -206,24 +234,28 @@ pub fn score_hand(request_js: JsValue) -> JsValue {
 /// Calculate shanten for a hand
 #[wasm_bindgen]
 pub fn calculate_shanten_js(hand: &str) -> JsValue {
-    match calculate_shanten_internal(hand) {
-        Ok((result, desc)) => serde_wasm_bindgen::to_value(&ShantenResponse {
-            success: true,
-            error: None,
-            shanten: Some(result.shanten),
-            best_type: Some(format!("{:?}", result.best_type)),
-            description: Some(desc),
-        })
-        .unwrap(),
-        Err(e) => serde_wasm_bindgen::to_value(&ShantenResponse {
+    serde_wasm_bindgen::to_value(&shanten_response(hand)).unwrap()
+}
+
+/// Calculate shanten for many hands in one call
+///
+/// Takes a JSON array of hand strings and returns one ShantenResponse per hand, in
+/// order; each hand succeeds or fails independently. If the input isn't an array of
+/// strings, a single failed response is returned.
+#[wasm_bindgen]
+pub fn calculate_shanten_batch(hands_js: JsValue) -> JsValue {
+    let responses = match serde_wasm_bindgen::from_value::<Vec<String>>(hands_js) {
+        Ok(hands) => calculate_shanten_batch_internal(&hands),
+        Err(e) => vec![ShantenResponse {
             success: false,
-            error: Some(e),
+            error: Some(format!("Failed to parse request: {}", e)),
             shanten: None,
             best_type: None,
             description: None,
-        })
-        .unwrap(),
-    }
+            bottleneck: None,
+        }],
+    };
+    serde_wasm_bindgen::to_value(&responses).unwrap()
 }
 
 /// Calculate ukeire (tile acceptance) for a hand
@@ -260,6 +292,18 @@ pub fn calculate_ukeire_js(hand: &str) -> JsValue {
     }
 }
 
+/// Rank every discard from a closed 14-tile hand by the ukeire it leaves
+#[wasm_bindgen]
+pub fn best_discards_js(hand: &str) -> JsValue {
+    serde_wasm_bindgen::to_value(&best_discards_response(hand)).unwrap()
+}
+
+/// List the tiles that complete a tenpai hand
+#[wasm_bindgen]
+pub fn calculate_waits_js(hand: &str) -> JsValue {
+    serde_wasm_bindgen::to_value(&waits_response(hand)).unwrap()
+}
+
 /// Validate a hand string without scoring
 #[wasm_bindgen]
 pub fn validate_hand(hand: &str) -> JsValue {
@@ -277,86 +321,186 @@ pub fn validate_hand(hand: &str) -> JsValue {
     }
 }
 
-// ============================================================================
-// Internal implementation functions
-// ============================================================================
-
-fn score_hand_internal(request: &ScoreRequest) -> Result<ScoringOutput, String> {
-    // Parse the hand
-    let parsed = parse_hand_with_aka(&request.hand).map_err(|e| e.to_string())?;
-    let counts = to_counts(&parsed.tiles);
-
-    // For dora counting, we need ALL tiles including those in called melds
-    let all_tiles_counts = {
-        let mut all_tiles = parsed.tiles.clone();
-        for called_meld in &parsed.called_melds {
-            all_tiles.extend(&called_meld.tiles);
-        }
-        to_counts(&all_tiles)
+/// Check a discard pond for Nagashi Mangan at exhaustive draw
+///
+/// Takes a JSON array of tile strings (the whole pond, none of them called) and
+/// returns a NagashiResponse with the mangan payment when it qualifies.
+#[wasm_bindgen]
+pub fn nagashi_mangan_js(discards_js: JsValue, is_dealer: bool) -> JsValue {
+    let response = match serde_wasm_bindgen::from_value::<Vec<String>>(discards_js) {
+        Ok(discards) => nagashi_response(&discards, is_dealer),
+        Err(e) => NagashiResponse {
+            success: false,
+            error: Some(format!("Failed to parse request: {}", e)),
+            payment: None,
+        },
     };
+    serde_wasm_bindgen::to_value(&response).unwrap()
+}
 
-    // Parse winds
-    let round_wind = parse_wind(&request.round_wind)?;
-    let seat_wind = parse_wind(&request.seat_wind)?;
-
-    // Determine win type
-    let win_type = if request.is_tsumo {
-        WinType::Tsumo
-    } else {
-        WinType::Ron
+/// Check whether a tenpai hand is furiten on the player's own discards
+///
+/// Takes the concealed hand (called melds in the usual notation) and a JSON array
+/// of discarded tile strings, and returns a FuritenResponse with the hand's waits.
+#[wasm_bindgen]
+pub fn check_furiten_js(hand: &str, discards_js: JsValue) -> JsValue {
+    let response = match serde_wasm_bindgen::from_value::<Vec<String>>(discards_js) {
+        Ok(discards) => furiten_response(hand, &discards),
+        Err(e) => FuritenResponse {
+            success: false,
+            error: Some(format!("Failed to parse request: {}", e)),
+            furiten: false,
+            waits: Vec::new(),
+        },
     };
+    serde_wasm_bindgen::to_value(&response).unwrap()
+}
 
-    // Check if hand has open melds
-    let has_open_melds = parsed.called_melds.iter().any(|m| m.meld.is_open());
+/// Validate a score request without scoring, reporting every problem at once
+///
+/// Takes a JSON-serialized ScoreRequest and returns an array of
+/// `{ error, error_code }` objects, empty when the request is valid.
+#[wasm_bindgen]
+pub fn validate_score_request(request_js: JsValue) -> JsValue {
+    let problems = match serde_wasm_bindgen::from_value::<ScoreRequest>(request_js) {
+        Ok(request) => validate_request_internal(&request),
+        Err(e) => vec![serde_json::json!({
+            "error": format!("Failed to parse request: {}", e),
+            "error_code": "invalid_request"
+        })],
+    };
+    serde_wasm_bindgen::to_value(&problems).unwrap()
+}
 
-    // Build game context
-    let mut context = GameContext::new(win_type, round_wind, seat_wind);
+// ============================================================================
+// Internal implementation functions
+// ============================================================================
 
-    if has_open_melds {
-        context = context.open();
+fn nagashi_response(discards: &[String], is_dealer: bool) -> NagashiResponse {
+    match parse_tile_list(discards) {
+        Ok(tiles) => NagashiResponse {
+            success: true,
+            error: None,
+            payment: calculate_nagashi_mangan(&tiles, is_dealer).map(|result| PaymentInfo {
+                total: result.payment.total,
+                from_discarder: result.payment.from_discarder,
+                from_dealer: result.payment.from_dealer,
+                from_non_dealer: result.payment.from_non_dealer,
+                total_with_sticks: result.total_with_sticks,
+            }),
+        },
+        Err(e) => NagashiResponse {
+            success: false,
+            error: Some(e.to_string()),
+            payment: None,
+        },
     }
+}
 
-    if request.is_riichi {
-        context = context.riichi();
-    }
-    if request.is_double_riichi {
-        context = context.double_riichi();
-    }
-    if request.is_ippatsu {
-        context = context.ippatsu();
-    }
-    if request.is_last_tile {
-        context = context.last_tile();
-    }
-    if request.is_rinshan {
-        context = context.rinshan();
-    }
-    if request.is_chankan {
-        context = context.chankan();
+fn furiten_response(hand: &str, discards: &[String]) -> FuritenResponse {
+    let parsed = parse_hand_with_aka(hand).and_then(|parsed| {
+        let discards = parse_tile_list(discards).map_err(|e| e.to_string())?;
+        Ok((parsed.concealed_counts(), discards))
+    });
+    match parsed {
+        Ok((counts, discards)) => FuritenResponse {
+            success: true,
+            error: None,
+            furiten: is_furiten(&counts, &discards),
+            waits: calculate_waits(&counts)
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+        },
+        Err(e) => FuritenResponse {
+            success: false,
+            error: Some(e.to_string()),
+            furiten: false,
+            waits: Vec::new(),
+        },
     }
-    if request.is_tenhou {
-        context = context.tenhou();
+}
+
+fn best_discards_response(hand: &str) -> BestDiscardsResponse {
+    let counts = parse_hand_with_aka(hand).and_then(|parsed| {
+        if !parsed.called_melds.is_empty() || parsed.tiles.len() != 14 {
+            return Err("Discard analysis needs a closed 14-tile hand".to_string());
+        }
+        Ok(parsed.concealed_counts())
+    });
+    match counts {
+        Ok(counts) => BestDiscardsResponse {
+            success: true,
+            error: None,
+            discards: Some(
+                best_discards(&counts)
+                    .into_iter()
+                    .map(|(tile, ukeire)| DiscardInfo {
+                        tile: tile.to_string(),
+                        shanten: ukeire.shanten,
+                        tiles: ukeire
+                            .tiles
+                            .iter()
+                            .map(|t| UkeireTileInfo {
+                                tile: t.tile.to_string(),
+                                available: t.available,
+                            })
+                            .collect(),
+                        total_count: ukeire.total_count,
+                    })
+                    .collect(),
+            ),
+        },
+        Err(e) => BestDiscardsResponse {
+            success: false,
+            error: Some(e),
+            discards: None,
+        },
     }
-    if request.is_chiihou {
-        context = context.chiihou();
+}
+
+fn waits_response(hand: &str) -> WaitsResponse {
+    match parse_hand_with_aka(hand) {
+        Ok(parsed) => WaitsResponse {
+            success: true,
+            error: None,
+            waits: Some(
+                calculate_waits(&parsed.concealed_counts())
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect(),
+            ),
+        },
+        Err(e) => WaitsResponse {
+            success: false,
+            error: Some(e.to_string()),
+            waits: None,
+        },
     }
+}
 
-    // Parse dora indicators
-    let dora_indicators = parse_tile_list(&request.dora_indicators)?;
-    let ura_dora_indicators = parse_tile_list(&request.ura_dora_indicators)?;
+fn validate_request_internal(request: &ScoreRequest) -> Vec<serde_json::Value> {
+    validate_request(request)
+        .iter()
+        .map(|e| {
+            serde_json::json!({
+                "error": e.to_string(),
+                "error_code": e.code()
+            })
+        })
+        .collect()
+}
 
-    context = context.with_dora(dora_indicators);
-    context = context.with_ura_dora(ura_dora_indicators);
-    context = context.with_aka(parsed.aka_count);
+fn score_hand_internal(request: &ScoreRequest) -> Result<ScoringOutput, ScoreError> {
+    // Hand (with any inline winning tile marker), winds, win type, dora, flags
+    let context = build_context(request)?;
+    let parsed = parse_request_hand(request)?;
+    let counts = parsed.concealed_counts();
 
-    // Parse winning tile if provided, otherwise we'll infer it
-    let explicit_winning_tile = if let Some(ref wt) = request.winning_tile {
-        let tile = parse_single_tile(wt)?;
-        context = context.with_winning_tile(tile);
-        true
-    } else {
-        false
-    };
+    // For dora counting, we need ALL tiles including those in called melds
+    let all_tiles_counts = parsed.all_counts();
+    let discards = parse_tile_list(&request.discards)?;
+    let explicit_winning_tile = context.winning_tile.is_some();
 
     // Decompose the hand
     let structures = if parsed.called_melds.is_empty() {
@@ -371,100 +515,101 @@ fn score_hand_internal(request: &ScoreRequest) -> Result<ScoringOutput, String>
     };
 
     if structures.is_empty() {
-        return Err("No valid hand structure found".to_string());
+        return Err(ScoreError::NoStructure);
     }
 
     // If no winning tile was specified, infer the best one by trying all unique tiles
-    let (best, context) = if explicit_winning_tile {
-        // Use the specified winning tile - score each structure interpretation
-        let mut best: Option<(HandStructure, YakuResult, ScoringResult)> = None;
-
-        for structure in &structures {
-            let yaku = detect_yaku_with_context(structure, &all_tiles_counts, &context);
-
-            // Skip interpretations with no yaku
-            if yaku.yaku_list.is_empty() {
-                continue;
-            }
-
-            let score = calculate_score(structure, &yaku, &context);
-
-            let is_better = match &best {
-                None => true,
-                Some((_, _, best_score)) => {
-                    score.payment.total > best_score.payment.total
-                        || (score.payment.total == best_score.payment.total
-                            && score.han > best_score.han)
-                }
-            };
-
-            if is_better {
-                best = Some((structure.clone(), yaku, score));
-            }
-        }
-        (best, context)
+    // (unless inference was disabled by the caller)
+    let (best, context) = if explicit_winning_tile || !request.infer_winning_tile {
+        // Use the specified winning tile (or none); interpretations with no yaku don't count
+        let best = rank_interpretations(&structures, &all_tiles_counts, &context)
+            .into_iter()
+            .next()
+            .filter(|best| best.has_yaku())
+            .ok_or(ScoreError::NoYaku)?;
+        ((best.structure, best.yaku, best.score), context)
     } else {
         // Infer the best winning tile by trying all unique tiles in the hand
-        infer_best_winning_tile(&structures, &all_tiles_counts, context, &parsed.tiles)
+        infer_best_winning_tile(
+            &structures,
+            &all_tiles_counts,
+            context,
+            &parsed.tiles,
+            &discards,
+        )?
     };
 
-    let (structure, yaku, score) = best.ok_or("No valid yaku found for this hand")?;
+    let (structure, yaku, score) = best;
+
+    // Ron is not allowed if any tile the hand waits on is among the player's own discards
+    // (tsumo is exempt). Inference already skips furiten tiles, so this only rejects a
+    // given winning tile.
+    if context.win_type == WinType::Ron
+        && let Some(winning_tile) = context.winning_tile
+    {
+        let mut tenpai_counts = counts.clone();
+        if let Some(count) = tenpai_counts.get_mut(&winning_tile) {
+            *count -= 1;
+        }
+        if is_furiten(&tenpai_counts, &discards) {
+            return Err(ScoreError::Furiten(winning_tile));
+        }
+    }
 
     // Convert to output format
-    let yaku_list: Vec<YakuInfo> = yaku
-        .yaku_list
+    let summary = ScoreSummary::from((&structure, &yaku, &score, &context));
+
+    let yaku_list: Vec<YakuInfo> = summary
+        .yaku
         .iter()
-        .map(|y| YakuInfo {
-            name: yaku_name(y),
-            han: if context.is_open {
-                y.han_open().unwrap_or(y.han())
-            } else {
-                y.han()
-            },
+        .map(|(y, han)| YakuInfo {
+            name: y.to_string(),
+            han: *han,
             is_yakuman: y.is_yakuman(),
         })
         .collect();
 
-    let total_han = yaku.total_han_with_dora();
-
     // Determine if winning tile was inferred
     let inferred_winning_tile = if !explicit_winning_tile {
-        context.winning_tile.map(|t| format!("{}", t))
+        summary.winning_tile.map(|t| format!("{}", t))
     } else {
         None
     };
 
+    let fu = &summary.fu;
     Ok(ScoringOutput {
         yaku: yaku_list,
-        han: yaku.total_han,
-        fu: score.fu.total,
+        han: summary.yaku_han,
+        fu: fu.total,
         dora: DoraInfo {
-            regular: yaku.regular_dora,
-            ura: yaku.ura_dora,
-            aka: yaku.aka_dora,
-            total: yaku.dora_count,
+            regular: summary.dora.regular,
+            ura: summary.dora.ura,
+            aka: summary.dora.aka,
+            total: summary.dora.total(),
         },
-        total_han,
-        score_level: score.score_level.name().to_string(),
+        total_han: summary.total_han,
+        score_level: summary.score_level.name().to_string(),
         payment: PaymentInfo {
-            total: score.payment.total,
-            from_discarder: score.payment.from_discarder,
-            from_dealer: score.payment.from_dealer,
-            from_non_dealer: score.payment.from_non_dealer,
+            total: summary.payment.total,
+            from_discarder: summary.payment.from_discarder,
+            from_dealer: summary.payment.from_dealer,
+            from_non_dealer: summary.payment.from_non_dealer,
+            total_with_sticks: summary.total_with_sticks,
         },
-        is_dealer: score.is_dealer,
-        is_counted_yakuman: score.is_counted_yakuman,
+        is_dealer: summary.is_dealer,
+        is_counted_yakuman: summary.is_counted_yakuman,
         fu_breakdown: FuBreakdownInfo {
-            base: score.fu.breakdown.base,
-            menzen_ron: score.fu.breakdown.menzen_ron,
-            tsumo: score.fu.breakdown.tsumo,
-            melds: score.fu.breakdown.melds,
-            pair: score.fu.breakdown.pair,
-            wait: score.fu.breakdown.wait,
-            raw_total: score.fu.breakdown.raw_total,
-            rounded: score.fu.total,
+            base: fu.breakdown.base,
+            menzen_ron: fu.breakdown.menzen_ron,
+            tsumo: fu.breakdown.tsumo,
+            melds: fu.breakdown.melds,
+            pair: fu.breakdown.pair,
+            wait: fu.breakdown.wait,
+            raw_total: fu.breakdown.raw_total,
+            rounded: fu.total,
+            floored_to_open_minimum: fu.breakdown.floored_to_open_minimum,
         },
-        hand_structure: format_structure(&structure),
+        hand_structure: format_structure(&summary.structure),
         inferred_winning_tile,
     })
 }
@@ -488,6 +633,31 @@ fn calculate_shanten_internal(hand: &str) -> Result<(ShantenResult, String), Str
     Ok((result, description))
 }
 
+fn shanten_response(hand: &str) -> ShantenResponse {
+    match calculate_shanten_internal(hand) {
+        Ok((result, desc)) => ShantenResponse {
+            success: true,
+            error: None,
+            shanten: Some(result.shanten),
+            best_type: Some(format!("{:?}", result.best_type)),
+            description: Some(desc),
+            bottleneck: result.bottleneck,
+        },
+        Err(e) => ShantenResponse {
+            success: false,
+            error: Some(e),
+            shanten: None,
+            best_type: None,
+            description: None,
+            bottleneck: None,
+        },
+    }
+}
+
+fn calculate_shanten_batch_internal(hands: &[String]) -> Vec<ShantenResponse> {
+    hands.iter().map(|hand| shanten_response(hand)).collect()
+}
+
 fn calculate_ukeire_internal(hand: &str) -> Result<UkeireResult, String> {
     let parsed = parse_hand_with_aka(hand).map_err(|e| e.to_string())?;
     let counts = to_counts(&parsed.tiles);
@@ -499,70 +669,75 @@ fn calculate_ukeire_internal(hand: &str) -> Result<UkeireResult, String> {
 // Helper functions
 // ============================================================================
 
-fn parse_wind(s: &str) -> Result<Honor, String> {
-    match s.to_lowercase().as_str() {
-        "east" | "e" | "1z" => Ok(Honor::East),
-        "south" | "s" | "2z" => Ok(Honor::South),
-        "west" | "w" | "3z" => Ok(Honor::West),
-        "north" | "n" | "4z" => Ok(Honor::North),
-        _ => Err(format!("Invalid wind: {}", s)),
-    }
-}
-
-fn parse_single_tile(s: &str) -> Result<Tile, String> {
-    Tile::try_from(s)
+fn parse_single_tile(s: &str) -> Result<Tile, ParseError> {
+    s.parse()
 }
 
 /// Infer the best winning tile by trying all unique tiles in the hand
+///
+/// On ron, a tile that would leave the hand furiten on `discards` is skipped.
+/// Fails with `NoStructure` when there is nothing to score, `Furiten` when every
+/// tile with a yaku is furiten, and `NoYaku` when the hand is complete but no
+/// winning tile gives any structure a yaku.
 fn infer_best_winning_tile(
     structures: &[HandStructure],
     all_tiles_counts: &TileCounts,
     base_context: GameContext,
     tiles: &[Tile],
-) -> (
-    Option<(HandStructure, YakuResult, ScoringResult)>,
-    GameContext,
-) {
+    discards: &[Tile],
+) -> Result<((HandStructure, YakuResult, ScoringResult), GameContext), ScoreError> {
+    if structures.is_empty() {
+        return Err(ScoreError::NoStructure);
+    }
+
     // Get unique tiles in the hand
     let unique_tiles: HashSet<Tile> = tiles.iter().copied().collect();
+    let concealed_counts = to_counts(tiles);
 
-    let mut best: Option<(HandStructure, YakuResult, ScoringResult)> = None;
-    let mut best_context = base_context.clone();
-    let mut best_score: Option<(u32, u8, u8)> = None; // (payment, han, 255-fu for comparison)
+    let mut best: Option<(Interpretation, GameContext)> = None;
+    let mut furiten_tile: Option<Tile> = None;
 
     for winning_tile in unique_tiles {
         let context = base_context.clone().with_winning_tile(winning_tile);
 
-        for structure in structures {
-            let yaku_result = detect_yaku_with_context(structure, all_tiles_counts, &context);
+        // Interpretations with no yaku don't count
+        let Some(candidate) = rank_interpretations(structures, all_tiles_counts, &context)
+            .into_iter()
+            .next()
+            .filter(|candidate| candidate.has_yaku())
+        else {
+            continue;
+        };
 
-            // Skip interpretations with no yaku
-            if yaku_result.yaku_list.is_empty() {
+        if context.win_type == WinType::Ron {
+            let mut tenpai_counts = concealed_counts.clone();
+            if let Some(count) = tenpai_counts.get_mut(&winning_tile) {
+                *count -= 1;
+            }
+            if is_furiten(&tenpai_counts, discards) {
+                furiten_tile = Some(furiten_tile.map_or(winning_tile, |t| t.min(winning_tile)));
                 continue;
             }
+        }
 
-            let score = calculate_score(structure, &yaku_result, &context);
-
-            // Compare: prefer higher payment, then higher han, then lower fu
-            let current = (score.payment.total, score.han, 255 - score.fu.total);
-
-            let is_better = match best_score {
-                None => true,
-                Some(best) => current > best,
-            };
+        let is_better = match &best {
+            None => true,
+            Some((current, _)) => compare_interpretations(&candidate, current).is_gt(),
+        };
 
-            if is_better {
-                best_score = Some(current);
-                best_context = context.clone();
-                best = Some((structure.clone(), yaku_result, score));
-            }
+        if is_better {
+            best = Some((candidate, context));
         }
     }
 
-    (best, best_context)
+    match (best, furiten_tile) {
+        (Some((best, context)), _) => Ok(((best.structure, best.yaku, best.score), context)),
+        (None, Some(tile)) => Err(ScoreError::Furiten(tile)),
+        (None, None) => Err(ScoreError::NoYaku),
+    }
 }
 
-fn parse_tile_list(tiles: &[String]) -> Result<Vec<agari::tile::Tile>, String> {
+fn parse_tile_list(tiles: &[String]) -> Result<Vec<agari::tile::Tile>, ParseError> {
     tiles.iter().map(|s| parse_single_tile(s)).collect()
 }
 
@@ -582,63 +757,6 @@ fn format_structure(structure: &HandStructure) -> String {
     }
 }
 
-fn yaku_name(yaku: &Yaku) -> String {
-    match yaku {
-        Yaku::Riichi => "Riichi".to_string(),
-        Yaku::Ippatsu => "Ippatsu".to_string(),
-        Yaku::MenzenTsumo => "Menzen Tsumo".to_string(),
-        Yaku::Tanyao => "Tanyao".to_string(),
-        Yaku::Pinfu => "Pinfu".to_string(),
-        Yaku::Iipeikou => "Iipeikou".to_string(),
-        Yaku::Yakuhai(honor) => {
-            let name = match honor {
-                Honor::East => "East",
-                Honor::South => "South",
-                Honor::West => "West",
-                Honor::North => "North",
-                Honor::White => "Haku",
-                Honor::Green => "Hatsu",
-                Honor::Red => "Chun",
-            };
-            format!("Yakuhai ({})", name)
-        }
-        Yaku::RinshanKaihou => "Rinshan Kaihou".to_string(),
-        Yaku::Chankan => "Chankan".to_string(),
-        Yaku::HaiteiRaoyue => "Haitei Raoyue".to_string(),
-        Yaku::HouteiRaoyui => "Houtei Raoyui".to_string(),
-        Yaku::DoubleRiichi => "Double Riichi".to_string(),
-        Yaku::Toitoi => "Toitoi".to_string(),
-        Yaku::SanshokuDoujun => "Sanshoku Doujun".to_string(),
-        Yaku::SanshokuDoukou => "Sanshoku Doukou".to_string(),
-        Yaku::Ittsu => "Ittsu".to_string(),
-        Yaku::Chiitoitsu => "Chiitoitsu".to_string(),
-        Yaku::Chanta => "Chanta".to_string(),
-        Yaku::SanAnkou => "San Ankou".to_string(),
-        Yaku::SanKantsu => "San Kantsu".to_string(),
-        Yaku::Honroutou => "Honroutou".to_string(),
-        Yaku::Shousangen => "Shousangen".to_string(),
-        Yaku::Honitsu => "Honitsu".to_string(),
-        Yaku::Junchan => "Junchan".to_string(),
-        Yaku::Ryanpeikou => "Ryanpeikou".to_string(),
-        Yaku::Chinitsu => "Chinitsu".to_string(),
-        Yaku::Tenhou => "Tenhou".to_string(),
-        Yaku::Chiihou => "Chiihou".to_string(),
-        Yaku::KokushiMusou => "Kokushi Musou".to_string(),
-        Yaku::Suuankou => "Suuankou".to_string(),
-        Yaku::Daisangen => "Daisangen".to_string(),
-        Yaku::Shousuushii => "Shousuushii".to_string(),
-        Yaku::Daisuushii => "Daisuushii".to_string(),
-        Yaku::Tsuuiisou => "Tsuuiisou".to_string(),
-        Yaku::Chinroutou => "Chinroutou".to_string(),
-        Yaku::Ryuuiisou => "Ryuuiisou".to_string(),
-        Yaku::ChuurenPoutou => "Chuuren Poutou".to_string(),
-        Yaku::SuuKantsu => "Suu Kantsu".to_string(),
-        Yaku::Kokushi13Wait => "Kokushi 13-Wait".to_string(),
-        Yaku::SuuankouTanki => "Suuankou Tanki".to_string(),
-        Yaku::JunseiChuurenPoutou => "Junsei Chuuren Poutou".to_string(),
-    }
-}
-
 // ============================================================================
 // Tests - WASM-specific functionality only
 // (Scoring/yaku/shanten logic is tested in agari-core)
@@ -647,7 +765,9 @@ fn yaku_name(yaku: &Yaku) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use agari::request::parse_wind;
     use agari::shanten::ShantenType;
+    use agari::tile::Honor;
 
     // ========================================================================
     // Helper functions for tests
@@ -668,8 +788,14 @@ mod tests {
             is_last_tile: false,
             is_rinshan: false,
             is_chankan: false,
+            chankan_tile: None,
             is_tenhou: false,
             is_chiihou: false,
+            is_open: false,
+            infer_winning_tile: true,
+            discards: vec![],
+            honba: 0,
+            riichi_sticks: 0,
         }
     }
 
@@ -691,6 +817,38 @@ mod tests {
         assert!(!output.yaku.is_empty());
     }
 
+    #[test]
+    fn test_score_request_with_inline_winning_tile_marker() {
+        // `=` marks a tsumo win on 6p without a separate winning_tile field
+        let request = make_request("234m345p456s678m6=6p");
+
+        let output = score_hand_internal(&request).unwrap();
+        assert!(output.yaku.iter().any(|y| y.name == "Menzen Tsumo"));
+        assert_eq!(output.inferred_winning_tile, None);
+    }
+
+    #[test]
+    fn test_score_request_with_honba() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.winning_tile = Some("6p".to_string());
+        let base = score_hand_internal(&request).unwrap().payment.total;
+
+        request.honba = 2;
+        let output = score_hand_internal(&request).unwrap();
+        assert_eq!(output.payment.total, base + 600);
+        assert_eq!(output.payment.from_discarder, Some(base + 600));
+    }
+
+    #[test]
+    fn test_score_request_with_riichi_sticks() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.winning_tile = Some("6p".to_string());
+        request.riichi_sticks = 2;
+
+        let payment = score_hand_internal(&request).unwrap().payment;
+        assert_eq!(payment.total_with_sticks, payment.total + 2000);
+    }
+
     #[test]
     fn test_score_request_invalid_hand() {
         let request = make_request("123m456p"); // Too few tiles
@@ -744,10 +902,349 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_score_request_open_all_sequences_no_pinfu() {
+        // Inference tries every tile, but an open hand must never get pinfu
+        let mut request = make_request("234m345p678m66p(456s)");
+        request.is_tsumo = true;
+
+        let result = score_hand_internal(&request).unwrap();
+
+        assert!(!result.yaku.iter().any(|y| y.name == "Pinfu"));
+        assert!(result.yaku.iter().any(|y| y.name == "Tanyao"));
+        assert_eq!(result.fu, 30);
+    }
+
+    #[test]
+    fn test_score_request_tenhou_with_open_pon_rejected() {
+        let mut request = make_request("123m456p789s22z(111z)");
+        request.is_tsumo = true;
+        request.is_tenhou = true;
+
+        assert_eq!(error_code(&request), "contradictory_flags");
+    }
+
+    #[test]
+    fn test_score_request_closed_kan_stays_closed() {
+        let mut request = make_request("[1111m]234m567p789s11z");
+        request.winning_tile = Some("5p".to_string());
+        request.is_tsumo = true;
+        request.is_riichi = true;
+
+        let output = score_hand_internal(&request).unwrap();
+        let names: Vec<_> = output.yaku.iter().map(|y| y.name.as_str()).collect();
+        assert!(names.contains(&"Riichi"));
+        assert!(names.contains(&"Menzen Tsumo"));
+    }
+
+    #[test]
+    fn test_score_request_chiihou_with_closed_kan_rejected() {
+        let mut request = make_request("123m456p789s22z[1111z]");
+        request.is_tsumo = true;
+        request.seat_wind = "south".to_string();
+        request.is_chiihou = true;
+
+        assert_eq!(error_code(&request), "contradictory_flags");
+    }
+
+    #[test]
+    fn test_score_request_closed_tenhou_scores_yakuman() {
+        let mut request = make_request("123m456p789s11122z");
+        request.is_tsumo = true;
+        request.is_tenhou = true;
+
+        let result = score_hand_internal(&request).unwrap();
+
+        assert!(result.yaku.iter().any(|y| y.name == "Tenhou"));
+        assert_eq!(result.score_level, "Yakuman");
+    }
+
+    // ========================================================================
+    // Error code tests
+    // ========================================================================
+
+    fn error_code(request: &ScoreRequest) -> &'static str {
+        score_hand_internal(request).unwrap_err().code()
+    }
+
+    #[test]
+    fn test_error_code_parse() {
+        let request = make_request("123m456x");
+        assert_eq!(error_code(&request), "parse_error");
+
+        let mut request = make_request("234m345p456s678m66p");
+        request.dora_indicators = vec!["8z".to_string()];
+        assert_eq!(error_code(&request), "parse_error");
+    }
+
+    #[test]
+    fn test_error_code_invalid_wind() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.seat_wind = "5z".to_string();
+        assert_eq!(error_code(&request), "invalid_wind");
+    }
+
+    #[test]
+    fn test_nagashi_response() {
+        let pond: Vec<String> = ["1m", "9p", "1z", "7z"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let response = nagashi_response(&pond, false);
+        assert!(response.success);
+        let payment = response.payment.unwrap();
+        assert_eq!(payment.total, 8000);
+        assert_eq!(payment.total_with_sticks, 8000);
+
+        let pond: Vec<String> = ["1m", "5p"].iter().map(|s| s.to_string()).collect();
+        let response = nagashi_response(&pond, true);
+        assert!(response.success);
+        assert!(response.payment.is_none());
+
+        let pond = vec!["8z".to_string()];
+        assert!(!nagashi_response(&pond, false).success);
+    }
+
+    #[test]
+    fn test_furiten_response() {
+        let response = furiten_response("23m456p789s11122z", &["4m".to_string()]);
+        assert!(response.success);
+        assert!(response.furiten);
+        assert_eq!(response.waits, vec!["1m", "4m"]);
+
+        let response = furiten_response("23m456p789s11122z", &["9m".to_string()]);
+        assert!(!response.furiten);
+
+        assert!(!furiten_response("23m456p789s11122z", &["0z".to_string()]).success);
+    }
+
+    #[test]
+    fn test_waits_response() {
+        let response = waits_response("(567s)23m456p11122z");
+        assert!(response.success);
+        assert_eq!(response.waits.unwrap(), vec!["1m", "4m"]);
+
+        assert!(
+            waits_response("1357m2468p1357s1z")
+                .waits
+                .unwrap()
+                .is_empty()
+        );
+        assert!(!waits_response("123x").success);
+    }
+
+    #[test]
+    fn test_best_discards_response() {
+        let response = best_discards_response("123m456p789s239m55z");
+        assert!(response.success);
+        let best = &response.discards.unwrap()[0];
+        assert_eq!(best.tile, "9m");
+        assert_eq!(best.shanten, 0);
+        assert_eq!(best.total_count, 7);
+
+        assert!(!best_discards_response("123m456p789s23m55z").success);
+        assert!(!best_discards_response("(111z)456p789s239m55z").success);
+    }
+
+    #[test]
+    fn test_validate_score_request_lists_all_problems() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.seat_wind = "5z".to_string();
+        request.winning_tile = Some("1m".to_string());
+
+        let problems = validate_request_internal(&request);
+        let codes: Vec<&str> = problems
+            .iter()
+            .map(|p| p["error_code"].as_str().unwrap())
+            .collect();
+        assert_eq!(codes, vec!["invalid_wind", "winning_tile_not_in_hand"]);
+
+        assert!(validate_request_internal(&make_request("234m345p456s678m66p")).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_wind_names_the_field() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.round_wind = "5z".to_string();
+        assert_eq!(
+            score_hand_internal(&request).unwrap_err(),
+            ScoreError::InvalidWind {
+                field: "round",
+                value: "5z".to_string()
+            }
+        );
+
+        let mut request = make_request("234m345p456s678m66p");
+        request.seat_wind = "middle".to_string();
+        let err = score_hand_internal(&request).unwrap_err();
+        assert_eq!(
+            err,
+            ScoreError::InvalidWind {
+                field: "seat",
+                value: "middle".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "Invalid seat wind: middle");
+    }
+
+    #[test]
+    fn test_error_code_no_structure() {
+        let request = make_request("123m456p789s11123z");
+        assert_eq!(error_code(&request), "no_structure");
+    }
+
+    #[test]
+    fn test_error_code_no_yaku() {
+        // Closed ron with a triplet and terminal pair: no pinfu, no tanyao
+        let request = make_request("111m456p789s234m11s");
+        assert_eq!(error_code(&request), "no_yaku");
+    }
+
+    #[test]
+    fn test_inference_distinguishes_no_yaku_from_no_structure() {
+        // Complete closed hand with no yaku on any wait when won by ron
+        let err = score_hand_internal(&make_request("111m456p789s234m11s")).unwrap_err();
+        assert_eq!(err, ScoreError::NoYaku);
+        assert_eq!(err.to_string(), "No valid yaku found for this hand");
+
+        // Incomplete hand: nothing to infer from
+        let err = score_hand_internal(&make_request("123m456p789s11123z")).unwrap_err();
+        assert_eq!(err, ScoreError::NoStructure);
+        assert_eq!(err.to_string(), "No valid hand structure found");
+    }
+
+    #[test]
+    fn test_error_code_contradictory_flags() {
+        let mut request = make_request("123m456p789s11122z");
+        request.is_tsumo = true;
+        request.is_tenhou = true;
+        request.is_chiihou = true;
+        assert_eq!(error_code(&request), "contradictory_flags");
+    }
+
+    #[test]
+    fn test_error_code_winning_tile_not_in_hand() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.winning_tile = Some("9p".to_string());
+
+        let err = score_hand_internal(&request).unwrap_err();
+
+        assert_eq!(err.code(), "winning_tile_not_in_hand");
+        assert_eq!(
+            err,
+            ScoreError::WinningTileNotInHand(Tile::suited(agari::tile::Suit::Pin, 9))
+        );
+    }
+
+    #[test]
+    fn test_error_code_too_many_copies() {
+        // 555m in hand plus two 5m indicators = 5 copies
+        let mut request = make_request("555m234p345s678s11z");
+        request.dora_indicators = vec!["5m".to_string()];
+        request.ura_dora_indicators = vec!["5m".to_string()];
+
+        assert_eq!(error_code(&request), "too_many_copies");
+    }
+
+    // ========================================================================
+    // Furiten tests
+    // ========================================================================
+
+    #[test]
+    fn test_furiten_ron_rejected() {
+        // 34567m waits on 2m/5m/8m; the player discarded 8m earlier
+        let mut request = make_request("234567m234p345s88s");
+        request.winning_tile = Some("2m".to_string());
+        request.discards = vec!["8m".to_string()];
+
+        let err = score_hand_internal(&request).unwrap_err();
+
+        assert_eq!(err.code(), "furiten");
+        assert_eq!(
+            err,
+            ScoreError::Furiten(Tile::suited(agari::tile::Suit::Man, 2))
+        );
+    }
+
+    #[test]
+    fn test_furiten_tsumo_scores() {
+        let mut request = make_request("234567m234p345s88s");
+        request.winning_tile = Some("2m".to_string());
+        request.discards = vec!["8m".to_string()];
+        request.is_tsumo = true;
+
+        assert!(score_hand_internal(&request).is_ok());
+    }
+
+    #[test]
+    fn test_inferred_winning_tile_skips_furiten() {
+        // 2m, 5m and 8m leave a 2-5-8m wait, furiten on the 8m discard; others don't
+        let mut request = make_request("234567m234p345s88s");
+        request.discards = vec!["8m".to_string()];
+
+        let result = score_hand_internal(&request).unwrap();
+        let inferred = result.inferred_winning_tile.unwrap();
+        assert!(!["2m", "5m", "8m"].contains(&inferred.as_str()));
+
+        // Every tile's wait includes itself, so discarding them all leaves no ron
+        request.discards = "2m 3m 4m 5m 6m 7m 2p 3p 4p 3s 4s 5s 8s"
+            .split(' ')
+            .map(String::from)
+            .collect();
+        assert_eq!(score_hand_internal(&request).unwrap_err().code(), "furiten");
+    }
+
+    #[test]
+    fn test_unrelated_discards_allow_ron() {
+        let mut request = make_request("234567m234p345s88s");
+        request.winning_tile = Some("2m".to_string());
+        request.discards = vec!["1z".to_string(), "9p".to_string()];
+
+        assert!(score_hand_internal(&request).is_ok());
+    }
+
     // ========================================================================
     // Inferred winning tile tests (WASM-specific feature)
     // ========================================================================
 
+    #[test]
+    fn test_no_infer_skips_pinfu_and_wait_fu() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.is_tsumo = true;
+        request.infer_winning_tile = false;
+
+        let result = score_hand_internal(&request).unwrap();
+
+        assert_eq!(result.fu, 30);
+        assert!(result.inferred_winning_tile.is_none());
+        assert!(!result.yaku.iter().any(|y| y.name == "Pinfu"));
+    }
+
+    #[test]
+    fn test_infer_allows_pinfu_tsumo() {
+        let mut request = make_request("234m345p456s678m66p");
+        request.is_tsumo = true;
+
+        let result = score_hand_internal(&request).unwrap();
+
+        assert_eq!(result.fu, 20);
+        assert!(result.yaku.iter().any(|y| y.name == "Pinfu"));
+    }
+
+    #[test]
+    fn test_infer_winning_tile_defaults_to_true() {
+        let request: ScoreRequest = serde_json::from_str(
+            r#"{"hand":"234m345p456s678m66p","winning_tile":null,"is_tsumo":false,
+            "is_riichi":false,"is_double_riichi":false,"is_ippatsu":false,
+            "round_wind":"east","seat_wind":"east","dora_indicators":[],
+            "ura_dora_indicators":[],"is_last_tile":false,"is_rinshan":false,
+            "is_chankan":false,"is_tenhou":false,"is_chiihou":false}"#,
+        )
+        .unwrap();
+
+        assert!(request.infer_winning_tile);
+    }
+
     #[test]
     fn test_inferred_winning_tile_set_when_not_provided() {
         let request = make_request("123m456p789s234m55s");
@@ -767,6 +1264,18 @@ mod tests {
         assert!(result.inferred_winning_tile.is_none());
     }
 
+    #[test]
+    fn test_inferred_winning_tile_kokushi_is_yakuman() {
+        let request = make_request("19m19p19s12345677z");
+
+        let result = score_hand_internal(&request).unwrap();
+
+        // Winning on the paired tile gives the 13-sided wait
+        assert_eq!(result.score_level, "Double Yakuman");
+        assert_eq!(result.inferred_winning_tile.as_deref(), Some("7z"));
+        assert!(result.yaku.iter().all(|y| y.is_yakuman));
+    }
+
     #[test]
     fn test_inferred_winning_tile_maximizes_score() {
         // Hand where ryanmen wait gives pinfu (higher score than tanki)
@@ -811,6 +1320,24 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_shanten_batch_errors_independently() {
+        let hands = vec![
+            "123m456p789s11z222s".to_string(),
+            "invalid".to_string(),
+            "1133557799m11p3s".to_string(),
+        ];
+        let responses = calculate_shanten_batch_internal(&hands);
+
+        assert_eq!(responses.len(), 3);
+        assert!(responses[0].success);
+        assert_eq!(responses[0].shanten, Some(-1));
+        assert!(!responses[1].success);
+        assert!(responses[1].error.is_some());
+        assert!(responses[2].success);
+        assert_eq!(responses[2].best_type.as_deref(), Some("Chiitoitsu"));
+    }
+
     // ========================================================================
     // Ukeire API tests
     // ========================================================================
@@ -833,7 +1360,7 @@ mod tests {
     }
 
     // ========================================================================
-    // parse_wind tests (WASM-specific helper)
+    // parse_wind tests (wind names accepted in requests)
     // ========================================================================
 
     #[test]
@@ -868,41 +1395,8 @@ mod tests {
 
     #[test]
     fn test_parse_wind_invalid() {
-        assert!(parse_wind("invalid").is_err());
-        assert!(parse_wind("5z").is_err());
-    }
-
-    // ========================================================================
-    // yaku_name tests (WASM-specific display helper)
-    // ========================================================================
-
-    #[test]
-    fn test_yaku_name_basic() {
-        assert_eq!(yaku_name(&Yaku::Riichi), "Riichi");
-        assert_eq!(yaku_name(&Yaku::Tanyao), "Tanyao");
-        assert_eq!(yaku_name(&Yaku::Pinfu), "Pinfu");
-    }
-
-    #[test]
-    fn test_yaku_name_yakuhai_winds() {
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::East)), "Yakuhai (East)");
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::South)), "Yakuhai (South)");
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::West)), "Yakuhai (West)");
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::North)), "Yakuhai (North)");
-    }
-
-    #[test]
-    fn test_yaku_name_yakuhai_dragons() {
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::White)), "Yakuhai (Haku)");
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::Green)), "Yakuhai (Hatsu)");
-        assert_eq!(yaku_name(&Yaku::Yakuhai(Honor::Red)), "Yakuhai (Chun)");
-    }
-
-    #[test]
-    fn test_yaku_name_yakuman() {
-        assert_eq!(yaku_name(&Yaku::KokushiMusou), "Kokushi Musou");
-        assert_eq!(yaku_name(&Yaku::Suuankou), "Suuankou");
-        assert_eq!(yaku_name(&Yaku::Daisangen), "Daisangen");
+        assert!(parse_wind("invalid").is_none());
+        assert!(parse_wind("5z").is_none());
     }
 
     // ========================================================================
@@ -49,13 +49,27 @@ export interface ScoreRequest {
   is_last_tile: boolean;
   is_rinshan: boolean;
   is_chankan: boolean;
+  /** The tile robbed from the added kan; must match the winning tile */
+  chankan_tile?: string;
   is_tenhou: boolean;
   is_chiihou: boolean;
+  /** Treat the hand as open even without open called melds (defaults to false) */
+  is_open?: boolean;
+  /** Infer the winning tile when none is given (defaults to true) */
+  infer_winning_tile?: boolean;
+  /** The player's own discards; a ron on a furiten wait is rejected */
+  discards?: string[];
+  /** Repeat counters on the table, added to the payment (defaults to 0) */
+  honba?: number;
+  /** Riichi sticks on the table, collected by the winner (defaults to 0) */
+  riichi_sticks?: number;
 }
 
 export interface ScoreResponse {
   success: boolean;
   error?: string;
+  /** Stable error code, e.g. "no_yaku" or "invalid_wind" */
+  error_code?: string;
   result?: ScoringOutput;
 }
 
@@ -92,6 +106,8 @@ export interface PaymentInfo {
   from_discarder?: number;
   from_dealer?: number;
   from_non_dealer?: number;
+  /** `total` plus 1000 per riichi stick collected */
+  total_with_sticks: number;
 }
 
 export interface FuBreakdownInfo {
@@ -103,6 +119,7 @@ export interface FuBreakdownInfo {
   wait: number;
   raw_total: number;
   rounded: number;
+  floored_to_open_minimum: boolean;
 }
 
 export interface ShantenResponse {
@@ -111,6 +128,7 @@ export interface ShantenResponse {
   shanten?: number;
   best_type?: string;
   description?: string;
+  bottleneck?: string;
 }
 
 export interface UkeireResponse {